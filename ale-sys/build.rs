@@ -3,6 +3,23 @@ use std::io;
 use std::path::{Path, PathBuf};
 use std::fs::{self, DirEntry};
 use std::{ffi::OsStr, env};
+use std::process::Command;
+
+/// The include directories of the ALE's sources, as set by its CMake build.
+const ALE_INCLUDE_DIRS: &[&str] = &[
+	"",
+	"common",
+	"controllers",
+	"emucore",
+	"emucore/m6502/src",
+	"emucore/m6502/src/bspf/src",
+	"environment",
+	"games",
+	"games/supported",
+	"os_dependent",
+	"external",
+	"external/TinyMT",
+];
 
 fn visit_dirs<F: FnMut(&DirEntry), G: Fn(&DirEntry) -> bool>(dir: &Path, cb: &mut F, filter: &G) -> io::Result<()> {
 	if dir.is_dir() {
@@ -27,29 +44,193 @@ fn main() {
 	let is_windows = target_env.contains("windows");
 	let is_linux = target_env.contains("linux");
 	let is_macos = target_env.contains("apple");
+	let dynamic = env::var_os("CARGO_FEATURE_DYNAMIC").is_some();
 	
+	// Use a pre-built ALE if one is given, rather than building it from the vendored source with CMake. The shim is
+	// still compiled against the vendored headers, so the pre-built ALE has to be the same version.
+	let ale_dir = project_root().join("ale");
+	let ale_version = ale_version(&ale_dir);
+	println!("cargo:rerun-if-env-changed=ALE_SYS_LIB_DIR");
+	let lib_dir = match env::var_os("ALE_SYS_LIB_DIR") {
+		Some(lib_dir) => {
+			let lib_dir = PathBuf::from(lib_dir);
+			let lib_filename = ale_lib_filename(&target_env, dynamic);
+			if !lib_dir.join(lib_filename).is_file() {
+				panic!("ALE_SYS_LIB_DIR: {} not found in {}", lib_filename, lib_dir.display());
+			}
+			match installed_ale_version(&lib_dir) {
+				Some(version) if version != ale_version => panic!(
+					"ALE_SYS_LIB_DIR: the pre-built ALE is version {}, but the vendored ALE is version {}",
+					version, ale_version
+				),
+				Some(_) => {},
+				None => println!(
+					"cargo:warning=ALE_SYS_LIB_DIR: could not find the version of the pre-built ALE, which must be the \
+					same as the vendored ALE ({})",
+					ale_version
+				),
+			}
+			lib_dir
+		},
+		None => build_ale(&ale_dir, &target_env, dynamic),
+	};
+
+	// Expose the version of the ALE, which identifies the encoding of its states
+	println!("cargo:rustc-env=ALE_SYS_ALE_VERSION={}", ale_version);
+
+	// Build the shim, which adds the entry points that the ALE's C wrapper doesn't provide. It is linked before the
+	// ALE, as it depends on it.
+	let shim_dir = project_root().join("shim");
+	let mut shim = cc::Build::new();
+	shim
+		.cpp(true)
+		.cpp_link_stdlib(None)
+		.flag_if_supported("-std=c++14")
+		.include(ale_dir.join("ale_py"))
+		.file(shim_dir.join("ale_rs_shim.cpp"));
+	for include_dir in ALE_INCLUDE_DIRS {
+		shim.include(ale_dir.join("src").join(include_dir));
+	}
+	// The shim only reaches into the internals of the ALE if it is linked statically against the ALE that was built
+	// from the headers it is compiled with, as the layout of a pre-built or shared library may not match them
+	if !dynamic && env::var_os("ALE_SYS_LIB_DIR").is_none() {
+		shim.define("ALE_RS_SHIM_INTERNALS", None);
+	}
+	if is_windows {
+		shim.define("WIN32", "1");
+	} else if is_macos {
+		shim.define("APPLE", "1");
+	}
+	shim.compile("ale_rs_shim");
+	println!("cargo:rerun-if-changed={}", shim_dir.display());
+
+	let ignore_files: &[&OsStr] = &["build".as_ref(), ".git".as_ref()];
+	let mut ale_files = vec![];
+	visit_dirs(&ale_dir, &mut |de| ale_files.push(de.path()), &|de| de.path().file_name().map(|n| !ignore_files.contains(&n)).unwrap_or(true)).expect(&format!("visit_dirs failed in {}", ale_dir.display()));
+
+	for path in ale_files {
+		println!("cargo:rerun-if-changed={}", path.display());
+	}
+	println!("cargo:rerun-if-changed=build.rs");
+	
+	// Tell rust to link C++ stdlib
+	let static_cxx = env::var_os("CARGO_FEATURE_STATIC_CXX").is_some();
+	if is_macos {
+		if static_cxx {
+			println!("cargo:warning=static-cxx is not supported on macOS, linking libc++ dynamically");
+		}
+		println!("cargo:rustc-link-lib=dylib=c++");
+	} else if is_linux {
+		if static_cxx {
+			link_static_stdcxx();
+		} else {
+			println!("cargo:rustc-link-lib=dylib=stdc++");
+		}
+	}
+	
+	// Link compiled ALE library. Cargo adds the directory to the library search path when running binaries and tests
+	// of the workspace, but a dynamically linked ALE has to be installed somewhere that the system can find it for
+	// binaries that are run in any other way.
+	//
+	// No rpath is emitted for the dynamic library, as Cargo only passes `rustc-link-arg` to the targets of the package
+	// whose build script emits it, so it would never reach the binaries that use the ALE. Instead, the directory is
+	// given to the build scripts of dependent packages as `DEP_ALE_LIB_DIR`, so that a binary can set its own rpath.
+	println!("cargo:rustc-link-search=native={}", lib_dir.display());
+	println!("cargo:lib_dir={}", lib_dir.display());
+	if dynamic {
+		println!("cargo:rustc-link-lib=dylib=ale_c");
+	} else {
+		println!("cargo:rustc-link-lib=static=ale_c_static");
+	}
+}
+
+/// Returns the filename of the ALE library that is linked, or its import library on Windows if it is dynamic.
+fn ale_lib_filename(target_env: &str, dynamic: bool) -> &'static str {
+	let is_msvc = target_env.contains("msvc");
+	match (dynamic, is_msvc) {
+		(false, true) => "ale_c_static.lib",
+		(false, false) => "libale_c_static.a",
+		(true, true) => "ale_c.lib",
+		(true, false) if target_env.contains("windows") => "libale_c.dll.a",
+		(true, false) if target_env.contains("apple") => "libale_c.dylib",
+		(true, false) => "libale_c.so",
+	}
+}
+
+/// Reads the version of the vendored ALE from its sources. It is given as `Version` in `ale_interface.hpp`, and in
+/// `CMakeLists.txt` as `ALEVERSION`, or by newer versions of the ALE as the project version.
+fn ale_version(ale_dir: &Path) -> String {
+	// The file, the start of the line that gives the version, and the text just before the version in that line
+	let sources: &[(&str, &str, &str)] = &[
+		("src/ale_interface.hpp", "static const std::string Version", "\""),
+		("CMakeLists.txt", "set(ALEVERSION", "\""),
+		("CMakeLists.txt", "project(", "VERSION "),
+	];
+	for (file, line_start, prefix) in sources {
+		let contents = match fs::read_to_string(ale_dir.join(file)) {
+			Ok(contents) => contents,
+			Err(_) => continue,
+		};
+		if let Some(version) = parse_ale_version(&contents, line_start, prefix) {
+			return version;
+		}
+	}
+	panic!("could not find the version of the ALE in {}, is the submodule checked out?", ale_dir.display());
+}
+
+/// Reads the version of a pre-built ALE from the `ale_interface.hpp` that was installed with it, if it can be found in
+/// the `include` directory next to `lib_dir`.
+fn installed_ale_version(lib_dir: &Path) -> Option<String> {
+	let include_dir = lib_dir.parent()?.join("include");
+	["ale/ale_interface.hpp", "ale_interface.hpp"]
+		.iter()
+		.filter_map(|header| fs::read_to_string(include_dir.join(header)).ok())
+		.find_map(|contents| parse_ale_version(&contents, "static const std::string Version", "\""))
+}
+
+/// Finds the version in the first line of `contents` that starts with `line_start`, just after `prefix`.
+fn parse_ale_version(contents: &str, line_start: &str, prefix: &str) -> Option<String> {
+	contents.lines()
+		.map(str::trim)
+		.filter(|line| line.starts_with(line_start))
+		.filter_map(|line| line.split_once(prefix).map(|(_, rest)| rest))
+		.map(|rest| rest.chars().take_while(|c| c.is_ascii_digit() || *c == '.').collect::<String>())
+		.find(|version| !version.is_empty())
+}
+
+/// Builds the ALE's C library from the vendored source with CMake, returning the directory that it was output to.
+fn build_ale(ale_dir: &Path, target_env: &str, dynamic: bool) -> PathBuf {
+	let is_windows = target_env.contains("windows");
+	let is_msvc = target_env.contains("msvc");
+	let is_macos = target_env.contains("apple");
+
 	// Get CMake profile from Cargo profile
 	let debug = env::var("DEBUG").expect("DEBUG env var required")
 		.parse::<bool>().expect("DEBUG not set to valid bool");
-	let profile = match env::var("OPT_LEVEL").unwrap().as_str() {
+	let mut profile = match env::var("OPT_LEVEL").unwrap().as_str() {
 		"0" => "Debug",
 		"1" | "2" | "3" => if debug { "RelWithDebInfo" } else { "Release" },
 		"s" | "z" => "MinSizeRel",
 		lvl => panic!("Unknown OPT_LEVEL: {}", lvl),
-	};
+	}.to_owned();
 
-	// Create temp dir for CMake operation
-	let temp_dir = tempdir::TempDir::new("ale-sys-build").expect("failed to create temp dir");
-	println!("temp_dir={}", temp_dir.path().display());
-	let lib_dir = out_dir().join("build").join("lib");
+	// Allow the CMake profile to be overridden, e.g. to get a fast emulator in a debug build
+	println!("cargo:rerun-if-env-changed=ALE_SYS_CMAKE_PROFILE");
+	if let Ok(override_profile) = env::var("ALE_SYS_CMAKE_PROFILE") {
+		const CMAKE_PROFILES: &[&str] = &["Debug", "Release", "RelWithDebInfo", "MinSizeRel"];
+		if !CMAKE_PROFILES.contains(&override_profile.as_str()) {
+			panic!("Unknown ALE_SYS_CMAKE_PROFILE: {} (expected one of {})", override_profile, CMAKE_PROFILES.join(", "));
+		}
+		profile = override_profile;
+	}
 
-	let cwd = env::current_dir().expect("failed to get current dir");
-	env::set_current_dir(&temp_dir).expect("failed to set current dir");
+	let lib_dir = out_dir().join("build").join("lib");
 
-	// Build using CMake
-	let ale_dir = project_root().join("ale");
-	let mut config = cmake::Config::new(&ale_dir);
+	// Build using CMake, entirely out of source. CMake is run in its build directory, so the current directory of this
+	// process, which is shared with other build scripts, is left alone.
+	let mut config = cmake::Config::new(ale_dir);
 	config
+		.out_dir(out_dir())
 		.define("USE_SDL", "OFF")
 		.define("USE_RLGLUE", "OFF")
 		.define("BUILD_EXAMPLES", "OFF")
@@ -57,38 +238,58 @@ fn main() {
 		.define("BUILD_CLI", "OFF")
 		.define("BUILD_C_LIB", "ON")
 		.define(format!("CMAKE_ARCHIVE_OUTPUT_DIRECTORY_{}", &profile.to_uppercase()), &lib_dir)
-		.profile(profile)
-		.build_target("ale-c-lib-static");
+		.profile(&profile);
 
-	if is_windows {
+	if dynamic {
+		// Shared libraries are output as library files, except for DLLs which are output as runtime files
+		config
+			.define(format!("CMAKE_LIBRARY_OUTPUT_DIRECTORY_{}", &profile.to_uppercase()), &lib_dir)
+			.define(format!("CMAKE_RUNTIME_OUTPUT_DIRECTORY_{}", &profile.to_uppercase()), &lib_dir)
+			.build_target("ale-c-lib");
+	} else {
+		config.build_target("ale-c-lib-static");
+	}
+
+	if is_msvc {
+		// Use the same C runtime as Rust, which is the release runtime even in debug builds, and is only linked
+		// statically with the `crt-static` target feature
+		let crt_static = env::var("CARGO_CFG_TARGET_FEATURE")
+			.map(|features| features.split(',').any(|feature| feature == "crt-static"))
+			.unwrap_or(false);
+		config.static_crt(crt_static).cflag("/DWIN32=1").cxxflag("/DWIN32=1");
+
+		// cmake-rs builds with `NUM_JOBS` jobs, but Visual Studio generators only build separate projects in parallel,
+		// so also have MSVC compile the sources of each project in parallel
+		if let Ok(jobs) = env::var("NUM_JOBS") {
+			config.cflag(format!("/MP{}", jobs)).cxxflag(format!("/MP{}", jobs));
+		}
+	} else if is_windows {
 		config.cflag("-DWIN32=1").cxxflag("-DWIN32=1");
 	} else if is_macos {
 		config.cflag("-DAPPLE=1").cxxflag("-DAPPLE=1");
 	}
 
 	let dst = config.build();
-	env::set_current_dir(&cwd).expect("failed to set current dir");
 	println!("dst={}", dst.display());
+	lib_dir
+}
 
-	let ignore_files: &[&OsStr] = &["build".as_ref(), ".git".as_ref()];
-	let mut ale_files = vec![];
-	visit_dirs(&ale_dir, &mut |de| ale_files.push(de.path()), &|de| de.path().file_name().map(|n| !ignore_files.contains(&n)).unwrap_or(true)).expect(&format!("visit_dirs failed in {}", ale_dir.display()));
+/// Links the static libstdc++ that is shipped with the C++ compiler.
+fn link_static_stdcxx() {
+	println!("cargo:rerun-if-env-changed=CXX");
+	let cxx = env::var("CXX").unwrap_or_else(|_| "c++".to_owned());
+	let output = Command::new(&cxx).arg("-print-file-name=libstdc++.a").output()
+		.unwrap_or_else(|e| panic!("failed to run {}: {}", cxx, e));
+	let lib_path = PathBuf::from(String::from_utf8(output.stdout).expect("invalid output from C++ compiler").trim());
 
-	for path in ale_files {
-		println!("cargo:rerun-if-changed={}", path.display());
+	// The compiler prints the bare filename if it can't find the library
+	match lib_path.parent() {
+		Some(lib_dir) if lib_path.is_absolute() => {
+			println!("cargo:rustc-link-search=native={}", lib_dir.display());
+			println!("cargo:rustc-link-lib=static=stdc++");
+		},
+		_ => panic!("static-cxx: {} could not find libstdc++.a, is the static C++ standard library installed?", cxx),
 	}
-	println!("cargo:rerun-if-changed=build.rs");
-	
-	// Tell rust to link C++ stdlib
-	if is_macos {
-		println!("cargo:rustc-link-lib=dylib=c++");
-	} else if is_linux {
-		println!("cargo:rustc-link-lib=dylib=stdc++");
-	}
-	
-	// Link compiled ALE static library
-	println!("cargo:rustc-link-search=native={}", lib_dir.display());
-	println!("cargo:rustc-link-lib=static=ale_c_static");
 }
 
 fn project_root() -> PathBuf {
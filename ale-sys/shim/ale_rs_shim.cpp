@@ -0,0 +1,90 @@
+#include "ale_rs_shim.h"
+
+#include <cstring>
+#include <exception>
+#include <iostream>
+#include <streambuf>
+#include <string>
+
+// Writes a null-terminated string to out, truncated to fit out_len bytes.
+static void writeString(const char *value, char *out, size_t out_len) {
+  if (out_len == 0) {
+    return;
+  }
+  std::strncpy(out, value, out_len - 1);
+  out[out_len - 1] = '\0';
+}
+
+int act2(ale::ALEInterface *ale, int player_a_action, int player_b_action) {
+#ifdef ALE_RS_SHIM_INTERNALS
+  // ALEInterface::act always sends PLAYER_B_NOOP, so drive the environment directly
+  return ale->environment->act(static_cast<ale::Action>(player_a_action),
+                               static_cast<ale::Action>(player_b_action));
+#else
+  // The layout of the ALE may not match the headers, so only its C API is used
+  (void)player_b_action;
+  return act(ale, player_a_action);
+#endif
+}
+
+bool act2Supported() {
+#ifdef ALE_RS_SHIM_INTERNALS
+  return true;
+#else
+  return false;
+#endif
+}
+
+bool tryLoadROM(ale::ALEInterface *ale, const char *rom_file, char *error, size_t error_len) {
+  try {
+    loadROM(ale, rom_file);
+    return true;
+  } catch (const std::exception &e) {
+    writeString(e.what(), error, error_len);
+  } catch (...) {
+    writeString("unknown exception", error, error_len);
+  }
+  return false;
+}
+
+bool trySaveScreenPNG(ale::ALEInterface *ale, const char *filename, char *error, size_t error_len) {
+  try {
+    saveScreenPNG(ale, filename);
+    return true;
+  } catch (const std::exception &e) {
+    writeString(e.what(), error, error_len);
+  } catch (...) {
+    writeString("unknown exception", error, error_len);
+  }
+  return false;
+}
+
+size_t getStringInto(ale::ALEInterface *ale, const char *key, char *value, size_t value_len) {
+  std::string setting = ale->getString(key);
+  writeString(setting.c_str(), value, value_len);
+  return setting.size();
+}
+
+// A stream buffer that discards everything written to it.
+class NullBuffer : public std::streambuf {
+protected:
+  int overflow(int c) override { return traits_type::not_eof(c); }
+  std::streamsize xsputn(const char *, std::streamsize n) override { return n; }
+};
+
+void setLoggerSilent(bool silent) {
+  static NullBuffer null_buffer;
+  static std::streambuf *cout_buffer = nullptr;
+  static std::streambuf *cerr_buffer = nullptr;
+  static std::streambuf *clog_buffer = nullptr;
+  if (silent && cout_buffer == nullptr) {
+    cout_buffer = std::cout.rdbuf(&null_buffer);
+    cerr_buffer = std::cerr.rdbuf(&null_buffer);
+    clog_buffer = std::clog.rdbuf(&null_buffer);
+  } else if (!silent && cout_buffer != nullptr) {
+    std::cout.rdbuf(cout_buffer);
+    std::cerr.rdbuf(cerr_buffer);
+    std::clog.rdbuf(clog_buffer);
+    cout_buffer = cerr_buffer = clog_buffer = nullptr;
+  }
+}
@@ -0,0 +1,35 @@
+#ifndef ALE_RS_SHIM_H
+#define ALE_RS_SHIM_H
+
+// Entry points that the ALE's C wrapper doesn't provide, built alongside it by build.rs.
+
+#include <stddef.h>
+
+#include <ale_c_wrapper.h>
+
+extern "C" {
+  // Applies an action for each player, and returns the reward of player A. player_b_action is one of the
+  // PLAYER_B_* actions.
+  int act2(ale::ALEInterface *ale, int player_a_action, int player_b_action);
+
+  // Indicates if act2 can control player B. This needs the ALE's environment, which isn't part of its public API, so
+  // it is only used when the ALE is built statically from the vendored source that the shim is compiled against.
+  // Otherwise act2 only controls player A.
+  bool act2Supported();
+
+  // Like loadROM and saveScreenPNG, but C++ exceptions are caught rather than unwinding into the caller. Returns false
+  // if an exception was thrown, after writing its message to error as a null-terminated string, truncated to fit
+  // error_len bytes.
+  bool tryLoadROM(ale::ALEInterface *ale, const char *rom_file, char *error, size_t error_len);
+  bool trySaveScreenPNG(ale::ALEInterface *ale, const char *filename, char *error, size_t error_len);
+
+  // Writes the value of a string setting to value as a null-terminated string, truncated to fit value_len bytes, and
+  // returns its full length. The C wrapper's getString returns a pointer into a temporary string, so it can't be used.
+  size_t getStringInto(ale::ALEInterface *ale, const char *key, char *value, size_t value_len);
+
+  // Discards everything written to std::cout, std::cerr and std::clog if silent is true, or restores them if it's
+  // false. The ALE has no logger mode that hides errors, and writes some messages to these streams directly.
+  void setLoggerSilent(bool silent);
+}
+
+#endif // ALE_RS_SHIM_H
@@ -85,6 +85,43 @@ pub mod root {
 	extern "C" {
 		pub fn act(ale: *mut root::ale::ALEInterface, action: ::std::os::raw::c_int) -> ::std::os::raw::c_int;
 	}
+	extern "C" {
+		pub fn act2(
+			ale: *mut root::ale::ALEInterface,
+			player_a_action: ::std::os::raw::c_int,
+			player_b_action: ::std::os::raw::c_int,
+		) -> ::std::os::raw::c_int;
+	}
+	extern "C" {
+		pub fn act2Supported() -> bool;
+	}
+	extern "C" {
+		pub fn tryLoadROM(
+			ale: *mut root::ale::ALEInterface,
+			rom_file: *const ::std::os::raw::c_char,
+			error: *mut ::std::os::raw::c_char,
+			error_len: usize,
+		) -> bool;
+	}
+	extern "C" {
+		pub fn trySaveScreenPNG(
+			ale: *mut root::ale::ALEInterface,
+			filename: *const ::std::os::raw::c_char,
+			error: *mut ::std::os::raw::c_char,
+			error_len: usize,
+		) -> bool;
+	}
+	extern "C" {
+		pub fn getStringInto(
+			ale: *mut root::ale::ALEInterface,
+			key: *const ::std::os::raw::c_char,
+			value: *mut ::std::os::raw::c_char,
+			value_len: usize,
+		) -> usize;
+	}
+	extern "C" {
+		pub fn setLoggerSilent(silent: bool);
+	}
 	extern "C" {
 		pub fn game_over(ale: *mut root::ale::ALEInterface) -> bool;
 	}
@@ -1,13 +1,47 @@
+//! Rust bindings to the C API of the [Arcade Learning Environment](https://github.com/mgbellemare/Arcade-Learning-Environment).
+//!
+//! The bindings in `bindings.rs` are generated from `wrapper.h` by bindgen, by running `cargo xtask gen-bindings` in
+//! the root of the repository, and committed so that clang isn't needed to build the crate. They should be
+//! regenerated whenever the ALE or the shim in `shim/` changes.
+//!
+//! The signatures of the bindings are checked against the C API here, so that a mismatch fails to compile:
+//! ```
+//! use std::os::raw::{c_char, c_int, c_uchar};
+//! use ale_sys::{ALEInterface, ALEState};
+//!
+//! let _: unsafe extern "C" fn() -> *mut ALEInterface = ale_sys::ALE_new;
+//! let _: unsafe extern "C" fn(*mut ALEInterface) = ale_sys::ALE_del;
+//! let _: unsafe extern "C" fn(*mut ALEInterface, *const c_char) -> c_int = ale_sys::getInt;
+//! let _: unsafe extern "C" fn(*mut ALEInterface, *const c_char, c_int) = ale_sys::setInt;
+//! let _: unsafe extern "C" fn(*mut ALEInterface, c_int) -> c_int = ale_sys::act;
+//! let _: unsafe extern "C" fn(*mut ALEInterface, c_int, c_int) -> c_int = ale_sys::act2;
+//! let _: unsafe extern "C" fn() -> bool = ale_sys::act2Supported;
+//! let _: unsafe extern "C" fn(*mut ALEInterface) -> bool = ale_sys::game_over;
+//! let _: unsafe extern "C" fn(*mut ALEInterface, *mut c_uchar) = ale_sys::getScreenRGB;
+//! let _: unsafe extern "C" fn(*mut ALEInterface) -> *mut ALEState = ale_sys::cloneSystemState;
+//! let _: unsafe extern "C" fn(*mut ALEInterface, *const c_char, *mut c_char, usize) -> bool = ale_sys::tryLoadROM;
+//! let _: unsafe extern "C" fn(*mut ALEInterface, *const c_char, *mut c_char, usize) -> usize = ale_sys::getStringInto;
+//! ```
+
 mod bindings;
 
+/// The version of these bindings.
+pub const VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// The version of the ALE that is linked, as read from the vendored sources by the build script.
+///
+/// A pre-built ALE that is linked with `ALE_SYS_LIB_DIR` must be the same version. The build script checks this
+/// against the `ale_interface.hpp` installed next to it, if it can find one, and warns if it can't.
+pub const ALE_VERSION: &str = env!("ALE_SYS_ALE_VERSION");
+
 pub use bindings::root::{
-	act,
+	act, act2, act2Supported,
 	ale::{ALEInterface, ALEState},
 	cloneState, cloneSystemState, decodeState, deleteState, encodeState, encodeStateLen, game_over,
 	getAvailableDifficulties, getAvailableDifficultiesSize, getAvailableModes, getAvailableModesSize, getBool,
 	getEpisodeFrameNumber, getFloat, getFrameNumber, getInt, getLegalActionSet, getLegalActionSize,
 	getMinimalActionSet, getMinimalActionSize, getRAM, getRAMSize, getScreen, getScreenGrayscale, getScreenHeight,
-	getScreenRGB, getScreenWidth, getString, lives, loadROM, loadState, reset_game, restoreState, restoreSystemState,
-	saveScreenPNG, saveState, setBool, setDifficulty, setFloat, setInt, setLoggerMode, setMode, setString, ALE_del,
-	ALE_new,
+	getScreenRGB, getScreenWidth, getString, getStringInto, lives, loadROM, loadState, reset_game, restoreState, restoreSystemState,
+	saveScreenPNG, saveState, setBool, setDifficulty, setFloat, setInt, setLoggerMode, setLoggerSilent, setMode,
+	setString, tryLoadROM, trySaveScreenPNG, ALE_del, ALE_new,
 };
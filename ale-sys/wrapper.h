@@ -1 +1,2 @@
 #include <ale_c_wrapper.h>
+#include "shim/ale_rs_shim.h"
@@ -1,7 +1,7 @@
 
-use std::time::{Instant, Duration};
+use std::time::Duration;
 
-use ale::{Ale, BundledRom};
+use ale::{Action, Ale, BundledRom, RealtimeClock};
 
 use pixels::{Error, Pixels, SurfaceTexture};
 use winit::dpi::{LogicalPosition, LogicalSize, PhysicalSize};
@@ -13,10 +13,12 @@ const SCREEN_WIDTH: u32 = 160;
 const SCREEN_HEIGHT: u32 = 210;
 
 const FRAME_DURATION: Duration = Duration::from_nanos(1_000_000_000 / 60);
+const MAX_FRAMES_PER_UPDATE: u32 = 5;
 
 fn main() -> Result<(), Error> {
 	let mut ale = Ale::new();
 	ale.load_rom(BundledRom::Breakout).expect("Illegal rom");
+	let noop = ale.noop_action();
 
 	let event_loop = EventLoop::new();
 	let mut input = WinitInputHelper::new();
@@ -26,8 +28,7 @@ fn main() -> Result<(), Error> {
 	let surface_texture = SurfaceTexture::new(p_width, p_height, surface);
 
 	let mut pixels = Pixels::new(SCREEN_WIDTH, SCREEN_HEIGHT, surface_texture)?;
-	let mut paused = false;
-	let mut prev_update = Instant::now();
+	let mut clock = RealtimeClock::new(FRAME_DURATION, MAX_FRAMES_PER_UPDATE);
 	println!("=== CONTROLS ===");
 	println!("Space - Start");
 	println!("A / Left - Flipper left");
@@ -36,37 +37,10 @@ fn main() -> Result<(), Error> {
 	println!();
 	println!("Paused: false");
 
-	let mut screen = vec![];
-	screen.resize(SCREEN_WIDTH as usize * SCREEN_HEIGHT as usize * 3, 0u8);
-
 	event_loop.run(move |event, _, control_flow| {
 		// The one and only event that winit_input_helper doesn't have for us...
 		if let Event::RedrawRequested(_) = event {
-			let screen_width = ale.screen_width();
-			let screen_height = ale.screen_height();
-			screen.resize(screen_width * screen_height * 3, 0);
-			ale.get_screen_rgb(&mut screen);
-
-			let frame = pixels.get_frame();
-			let mut x = 0;
-			let mut y = 0;
-			for pixel in frame.chunks_exact_mut(4) {
-				if x < screen_width && y < screen_height {
-					pixel[0] = screen[(y * screen_width + x) as usize * 3    ]; // R
-					pixel[1] = screen[(y * screen_width + x) as usize * 3 + 1]; // G
-					pixel[2] = screen[(y * screen_width + x) as usize * 3 + 2]; // B
-				} else {
-					pixel[0] = 0xff; // R
-					pixel[1] = 0x00; // G
-					pixel[2] = 0x00; // B
-				}
-				pixel[3] = 0xff; // A
-				x += 1;
-				if x >= screen_width {
-					x = 0;
-					y += 1;
-				}
-			}
+			ale.get_screen_rgba(pixels.get_frame());
 			pixels.render();
 		}
 
@@ -80,11 +54,12 @@ fn main() -> Result<(), Error> {
 			}
 			// Pause
 			if input.key_pressed(VirtualKeyCode::P) {
-				paused = !paused;
-				println!("Paused: {}", paused);
-				if !paused {
-					prev_update = Instant::now();
+				if clock.is_paused() {
+					clock.resume();
+				} else {
+					clock.pause();
 				}
+				println!("Paused: {}", clock.is_paused());
 			}
 			// Reset
 			if input.key_pressed(VirtualKeyCode::R) {
@@ -94,33 +69,29 @@ fn main() -> Result<(), Error> {
 			
 			// Get action
 			let action = if input.key_held(VirtualKeyCode::Left) || input.key_held(VirtualKeyCode::A) {
-				Some(4)
+				Some(Action::Left as i32)
 			} else if input.key_held(VirtualKeyCode::Right) || input.key_held(VirtualKeyCode::D) {
-				Some(3)
+				Some(Action::Right as i32)
 			} else if input.key_held(VirtualKeyCode::Space) {
-				Some(1)
-			} else if !paused {
-				Some(0)
+				Some(Action::Fire as i32)
+			} else if !clock.is_paused() {
+				Some(noop)
 			} else {
 				None
 			};
 			
 			// Update
-			let now = Instant::now();
-			let mut diff = now - prev_update;
-			if diff > 5 * FRAME_DURATION {
-				diff = 5 * FRAME_DURATION;
-				println!("Warning: skip of {}s occured", (diff - 5 * FRAME_DURATION).as_secs_f64());
+			let tick = clock.tick();
+			if tick.skipped > 0 {
+				println!("Warning: skipped {} frames", tick.skipped);
 			}
-			while diff > FRAME_DURATION {
-				diff -= FRAME_DURATION;
-				prev_update = now;
+			for _ in 0..tick.frames {
 				if let Some(action) = action {
 					if ale.legal_action_set().contains(&action) {
 						//println!("Update: {}", action);
 						ale.act(action);
 					} else {
-						println!("Warning: illegal action: {}", action);
+						println!("Warning: illegal action: {}", ale::action_name(action));
 					}
 				}
 			}
@@ -137,14 +108,17 @@ fn main() -> Result<(), Error> {
 				hidpi_factor = factor;
 			}
 			// Resize the window
-			if let Some(size) = input.window_resized() {
+			let resized = input.window_resized();
+			if let Some(size) = resized {
 				p_width = size.width;
 				p_height = size.height;
 				pixels.resize(p_width, p_height);
 			}
 			
-			// Request redraw
-			window.request_redraw();
+			// Request redraw, only if there is something new to draw
+			if ale.frame_dirty() || resized.is_some() {
+				window.request_redraw();
+			}
 		}
 	});
 }
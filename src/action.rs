@@ -0,0 +1,116 @@
+/// The 18 actions of the Atari 2600 controller, numbered as in the ALE.
+///
+/// The numbering matches the actions returned by [`Ale::legal_action_set`](crate::Ale::legal_action_set), so an
+/// action can be converted to the integer used by the ALE with `as i32`.
+///
+/// # Examples
+/// ```
+/// # use ale::Action;
+/// assert_eq!(Action::Noop as i32, 0);
+/// assert_eq!(Action::Fire as i32, 1);
+/// assert_eq!(Action::DownLeftFire as i32, 17);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(i32)]
+pub enum Action {
+	Noop = 0,
+	Fire = 1,
+	Up = 2,
+	Right = 3,
+	Left = 4,
+	Down = 5,
+	UpRight = 6,
+	UpLeft = 7,
+	DownRight = 8,
+	DownLeft = 9,
+	UpFire = 10,
+	RightFire = 11,
+	LeftFire = 12,
+	DownFire = 13,
+	UpRightFire = 14,
+	UpLeftFire = 15,
+	DownRightFire = 16,
+	DownLeftFire = 17,
+}
+/// The number of the ALE's `PLAYER_B_NOOP` action. The actions of player B follow it, in the same order as the actions
+/// of player A.
+pub(crate) const PLAYER_B_NOOP: i32 = 18;
+
+impl Action {
+	/// All of the actions, in order.
+	pub const ALL: [Action; 18] = [
+		Action::Noop,
+		Action::Fire,
+		Action::Up,
+		Action::Right,
+		Action::Left,
+		Action::Down,
+		Action::UpRight,
+		Action::UpLeft,
+		Action::DownRight,
+		Action::DownLeft,
+		Action::UpFire,
+		Action::RightFire,
+		Action::LeftFire,
+		Action::DownFire,
+		Action::UpRightFire,
+		Action::UpLeftFire,
+		Action::DownRightFire,
+		Action::DownLeftFire,
+	];
+
+	/// Converts an action from the integer used by the ALE, returning `None` if it isn't a valid action.
+	///
+	/// # Examples
+	/// ```
+	/// # use ale::Action;
+	/// assert_eq!(Action::from_i32(4), Some(Action::Left));
+	/// assert_eq!(Action::from_i32(18), None);
+	/// for &action in Action::ALL.iter() {
+	///     assert_eq!(Action::from_i32(action as i32), Some(action));
+	/// }
+	/// ```
+	pub fn from_i32(action: i32) -> Option<Action> {
+		if action >= 0 && (action as usize) < Action::ALL.len() {
+			Some(Action::ALL[action as usize])
+		} else {
+			None
+		}
+	}
+
+	/// Returns the name of the action, e.g. `"NOOP"` or `"UPLEFTFIRE"`. See [`action_name`].
+	pub fn name(self) -> &'static str {
+		match self {
+			Action::Noop => "NOOP",
+			Action::Fire => "FIRE",
+			Action::Up => "UP",
+			Action::Right => "RIGHT",
+			Action::Left => "LEFT",
+			Action::Down => "DOWN",
+			Action::UpRight => "UPRIGHT",
+			Action::UpLeft => "UPLEFT",
+			Action::DownRight => "DOWNRIGHT",
+			Action::DownLeft => "DOWNLEFT",
+			Action::UpFire => "UPFIRE",
+			Action::RightFire => "RIGHTFIRE",
+			Action::LeftFire => "LEFTFIRE",
+			Action::DownFire => "DOWNFIRE",
+			Action::UpRightFire => "UPRIGHTFIRE",
+			Action::UpLeftFire => "UPLEFTFIRE",
+			Action::DownRightFire => "DOWNRIGHTFIRE",
+			Action::DownLeftFire => "DOWNLEFTFIRE",
+		}
+	}
+}
+
+/// Returns the name of an action, e.g. `"NOOP"` or `"UPLEFTFIRE"`, or `"UNKNOWN"` if it isn't a valid action.
+///
+/// The names are the same as those used by OpenAI Gym, which makes this useful for logging.
+///
+/// # Examples
+/// ```
+/// assert_eq!(ale::action_name(0), "NOOP");
+/// assert_eq!(ale::action_name(12), "LEFTFIRE");
+/// assert_eq!(ale::action_name(-1), "UNKNOWN");
+/// ```
+pub fn action_name(action: i32) -> &'static str { Action::from_i32(action).map(Action::name).unwrap_or("UNKNOWN") }
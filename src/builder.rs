@@ -0,0 +1,149 @@
+use std::convert::TryInto;
+
+use crate::{Action, Ale, AleError, BundledRom, Settings};
+
+/// Builder for an [`Ale`], created by [`Ale::builder`].
+///
+/// # Examples
+/// ```
+/// # use ale::{Action, Ale, BundledRom};
+/// let mut ale = Ale::builder().restricted_actions(&[Action::Noop, Action::Left, Action::Right]).build();
+/// ale.load_rom(BundledRom::Breakout).unwrap();
+/// assert_eq!(ale.action_set(), vec![0, 4, 3]);
+/// ale.act_index(1); // Left
+/// assert!(ale.try_act(Action::Fire as i32).is_err());
+/// ```
+///
+/// Settings only take effect when a ROM is loaded, so [`AleBuilder::build_with_rom`] applies them and then loads the ROM
+/// in the right order:
+/// ```
+/// # use ale::{Ale, BundledRom};
+/// let mut ale = Ale::builder()
+///     .frame_skip(4)
+///     .repeat_action_probability(0.0)
+///     .random_seed(42)
+///     .build_with_rom(BundledRom::Breakout)
+///     .unwrap();
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct AleBuilder {
+	settings: Settings,
+	restricted_actions: Option<Vec<Action>>,
+}
+impl AleBuilder {
+	/// Creates a new builder, with the default settings.
+	pub fn new() -> AleBuilder { AleBuilder::default() }
+
+	/// Sets the settings that are applied to the emulator when it is built, replacing any settings that were set
+	/// before.
+	///
+	/// These are applied before any ROM is loaded, so they take effect when the ROM is loaded.
+	pub fn settings(mut self, settings: Settings) -> AleBuilder {
+		self.settings = settings;
+		self
+	}
+
+	/// Sets the number of frames that each action is repeated for, i.e. the `frame_skip` setting.
+	///
+	/// # Panics
+	/// If `frame_skip` is `0`, or too large to fit into an `i32`.
+	pub fn frame_skip(mut self, frame_skip: u32) -> AleBuilder {
+		assert!(frame_skip >= 1, "Frame skip must be at least 1");
+		self.settings.set_int("frame_skip", frame_skip.try_into().expect("frame skip too large"));
+		self
+	}
+
+	/// Sets the probability that the previous action is repeated instead of the chosen one, i.e. the
+	/// `repeat_action_probability` setting.
+	///
+	/// # Panics
+	/// If `probability` is not between `0.0` and `1.0`.
+	pub fn repeat_action_probability(mut self, probability: f32) -> AleBuilder {
+		assert!(
+			(0.0..=1.0).contains(&probability),
+			"Repeat action probability must be between 0 and 1, got {}",
+			probability
+		);
+		self.settings.set_float("repeat_action_probability", probability);
+		self
+	}
+
+	/// Sets the seed of the emulator's pseudorandomness, i.e. the `random_seed` setting.
+	pub fn random_seed(mut self, seed: i32) -> AleBuilder {
+		self.settings.set_int("random_seed", seed);
+		self
+	}
+
+	/// Sets whether consecutive frames are averaged to remove flicker, i.e. the `color_averaging` setting.
+	pub fn color_averaging(mut self, color_averaging: bool) -> AleBuilder {
+		self.settings.set_bool("color_averaging", color_averaging);
+		self
+	}
+
+	/// Restricts the actions available to the agent to the subset given.
+	///
+	/// The action set of the emulator, as returned by [`Ale::action_set`], will contain only these actions, in the order
+	/// given. Stepping the emulator with any other action returns [`AleError::RestrictedAction`], or panics.
+	///
+	/// The actions must be distinct, and there must be at least one. This is checked when the emulator is built, and
+	/// again against the game's legal action set when a ROM is loaded by [`AleBuilder::build_with_rom`].
+	pub fn restricted_actions(mut self, actions: &[Action]) -> AleBuilder {
+		self.restricted_actions = Some(actions.to_vec());
+		self
+	}
+
+	/// Builds the emulator.
+	///
+	/// No ROM is loaded, so one should be loaded for the settings to take effect. See [`AleBuilder::build_with_rom`].
+	///
+	/// # Panics
+	/// If the emulator could not be created, or if the restricted actions are invalid. See [`AleBuilder::try_build`].
+	pub fn build(self) -> Ale { self.try_build().unwrap_or_else(|e| panic!("{}", e)) }
+
+	/// Builds the emulator, and loads a bundled game with the settings applied.
+	///
+	/// Returns an error if the emulator could not be created, if the ROM could not be loaded, or if the restricted
+	/// actions are invalid or not legal in the game.
+	pub fn build_with_rom(self, rom: BundledRom) -> Result<Ale, AleError> {
+		let mut ale = self.try_build()?;
+		ale.load_rom(rom)?;
+		if let Some(actions) = ale.wrapper.restricted_actions.clone() {
+			let legal = ale.legal_action_set().to_vec();
+			validate_restricted_actions(&actions, &legal)?;
+		}
+		Ok(ale)
+	}
+
+	/// Builds the emulator, returning an error if it could not be created, or if the restricted actions are invalid.
+	///
+	/// No ROM is loaded, so the restricted actions are checked against every action of the controller, which is the
+	/// legal action set of every game.
+	///
+	/// # Examples
+	/// ```
+	/// # use ale::{Action, Ale, AleError};
+	/// let result = Ale::builder().restricted_actions(&[Action::Left, Action::Left]).try_build();
+	/// assert!(matches!(result, Err(AleError::InvalidActionRestriction { .. })));
+	/// ```
+	pub fn try_build(self) -> Result<Ale, AleError> {
+		let restricted_actions: Option<Vec<i32>> =
+			self.restricted_actions.map(|actions| actions.iter().map(|&a| a as i32).collect());
+		if let Some(actions) = &restricted_actions {
+			let legal: Vec<i32> = Action::ALL.iter().map(|&a| a as i32).collect();
+			validate_restricted_actions(actions, &legal)?;
+		}
+		let mut ale = Ale::try_new()?;
+		self.settings.apply(&mut ale);
+		ale.wrapper.restricted_actions = restricted_actions;
+		Ok(ale)
+	}
+}
+
+/// Checks that restricted actions are a non-empty set of distinct actions from `legal`.
+fn validate_restricted_actions(actions: &[i32], legal: &[i32]) -> Result<(), AleError> {
+	let distinct = actions.iter().enumerate().all(|(i, action)| !actions[..i].contains(action));
+	if actions.is_empty() || !distinct || !actions.iter().all(|action| legal.contains(action)) {
+		return Err(AleError::InvalidActionRestriction { actions: actions.to_vec(), legal: legal.to_vec() });
+	}
+	Ok(())
+}
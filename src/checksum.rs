@@ -0,0 +1,60 @@
+//! SHA-1 checksums of ROMs, used to check that a ROM is the one that the ALE expects.
+
+use crate::AleError;
+
+/// The known-good checksums of the ROMs that the ALE supports, in the format output by `sha1sum`. These are committed
+/// with the source, rather than taken from the downloaded ROMs, so that a corrupted or different dump is caught.
+const ROM_SHA1SUMS: &str = include_str!("rom_sha1sums.txt");
+
+/// Returns the SHA-1 checksum of `data`.
+pub(crate) fn sha1(data: &[u8]) -> [u8; 20] { sha1::Sha1::from(data).digest().bytes() }
+
+/// Returns the known-good checksum of the ROM with the filename given, if there is one.
+pub(crate) fn known_sha1(filename: &str) -> Option<[u8; 20]> {
+	ROM_SHA1SUMS.lines().filter(|line| !line.starts_with('#')).find_map(|line| {
+		let mut parts = line.split_whitespace();
+		let hex = parts.next()?;
+		// `sha1sum` marks files that were read in binary mode with a `*`
+		let name = parts.next()?.trim_start_matches('*');
+		if name == filename {
+			parse_hex(hex)
+		} else {
+			None
+		}
+	})
+}
+
+/// Parses a checksum from 40 hexadecimal digits.
+fn parse_hex(hex: &str) -> Option<[u8; 20]> {
+	if hex.len() != 40 {
+		return None;
+	}
+	let mut sha1 = [0; 20];
+	for (i, byte) in sha1.iter_mut().enumerate() {
+		*byte = u8::from_str_radix(hex.get(i * 2..i * 2 + 2)?, 16).ok()?;
+	}
+	Some(sha1)
+}
+
+/// Formats a checksum as 40 lowercase hexadecimal digits, as output by `sha1sum`.
+pub(crate) fn to_hex(sha1: &[u8; 20]) -> String { sha1.iter().map(|byte| format!("{:02x}", byte)).collect() }
+
+/// Checks that `data` matches the known-good checksum of the ROM with the filename given, returning an error if it
+/// doesn't.
+///
+/// ROMs that have no checksum in the table yet aren't checked, so that they can still be loaded.
+pub(crate) fn verify_known_sha1(filename: &str, data: &[u8]) -> Result<(), AleError> {
+	match known_sha1(filename) {
+		Some(expected) => verify_sha1(filename, data, expected),
+		None => Ok(()),
+	}
+}
+
+/// Checks that the checksum of `data` is `expected`, returning an error naming `rom` if it isn't.
+pub(crate) fn verify_sha1(rom: &str, data: &[u8], expected: [u8; 20]) -> Result<(), AleError> {
+	let actual = sha1(data);
+	if actual != expected {
+		return Err(AleError::RomChecksumMismatch { rom: rom.to_owned(), expected, actual });
+	}
+	Ok(())
+}
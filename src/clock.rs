@@ -0,0 +1,116 @@
+use std::convert::TryInto;
+use std::time::{Duration, Instant};
+
+/// The result of a [`RealtimeClock`] tick.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ClockTick {
+	/// The number of frames that should be advanced.
+	pub frames:  u32,
+	/// The number of frames that were owed, but dropped because more than the maximum had built up.
+	pub skipped: u32,
+}
+
+/// Clock used to run the emulator in real time, e.g. when playing interactively.
+///
+/// Each tick reports the number of whole frames that have elapsed since the last tick. Any left over time is carried
+/// over to the next tick, so no time is lost to rounding.
+///
+/// # Examples
+/// ```
+/// # use std::time::{Duration, Instant};
+/// # use ale::RealtimeClock;
+/// let start = Instant::now();
+/// let frame = Duration::from_millis(10);
+/// let mut clock = RealtimeClock::starting_at(frame, 5, start);
+///
+/// // 2.5 frames have elapsed
+/// assert_eq!(clock.tick_at(start + frame * 5 / 2).frames, 2);
+/// // The left over half frame is carried over
+/// assert_eq!(clock.tick_at(start + frame * 3).frames, 1);
+///
+/// // After a stall, only the maximum number of frames is advanced, and the rest are reported as skipped
+/// let tick = clock.tick_at(start + frame * 11);
+/// assert_eq!((tick.frames, tick.skipped), (5, 3));
+///
+/// // Time spent paused is not counted
+/// clock.pause_at(start + frame * 11);
+/// assert_eq!(clock.tick_at(start + frame * 20).frames, 0);
+/// clock.resume_at(start + frame * 20);
+/// assert_eq!(clock.tick_at(start + frame * 21).frames, 1);
+/// ```
+#[derive(Debug, Clone)]
+pub struct RealtimeClock {
+	frame_duration: Duration,
+	max_frames: u32,
+	accumulated: Duration,
+	prev: Instant,
+	paused: bool,
+}
+impl RealtimeClock {
+	/// Creates a new clock, starting now.
+	///
+	/// At most `max_frames` frames are advanced per tick, to stop the emulator from trying to catch up after a stall.
+	///
+	/// # Panics
+	/// If `frame_duration` is zero.
+	pub fn new(frame_duration: Duration, max_frames: u32) -> RealtimeClock {
+		RealtimeClock::starting_at(frame_duration, max_frames, Instant::now())
+	}
+
+	/// Creates a new clock, starting at the instant given.
+	///
+	/// # Panics
+	/// If `frame_duration` is zero.
+	pub fn starting_at(frame_duration: Duration, max_frames: u32, start: Instant) -> RealtimeClock {
+		assert!(frame_duration > Duration::from_secs(0), "Frame duration must be non-zero");
+		RealtimeClock { frame_duration, max_frames, accumulated: Duration::from_secs(0), prev: start, paused: false }
+	}
+
+	/// Returns the number of frames that have elapsed since the last tick.
+	pub fn tick(&mut self) -> ClockTick { self.tick_at(Instant::now()) }
+
+	/// Returns the number of frames that have elapsed between the last tick and `now`.
+	pub fn tick_at(&mut self, now: Instant) -> ClockTick {
+		if self.paused {
+			return ClockTick::default();
+		}
+		self.accumulated += now.saturating_duration_since(self.prev);
+		self.prev = now;
+
+		let frame_nanos = self.frame_duration.as_nanos();
+		let accumulated_nanos = self.accumulated.as_nanos();
+		let elapsed_frames: u32 = (accumulated_nanos / frame_nanos).try_into().unwrap_or(u32::MAX);
+		self.accumulated = Duration::from_nanos((accumulated_nanos % frame_nanos) as u64);
+
+		let frames = elapsed_frames.min(self.max_frames);
+		ClockTick { frames, skipped: elapsed_frames - frames }
+	}
+
+	/// Pauses the clock. No frames will elapse until the clock is resumed.
+	pub fn pause(&mut self) { self.pause_at(Instant::now()) }
+
+	/// Pauses the clock at the instant given.
+	///
+	/// Time elapsed before the pause is kept, and is reported by the first tick after the clock is resumed.
+	pub fn pause_at(&mut self, now: Instant) {
+		if !self.paused {
+			self.accumulated += now.saturating_duration_since(self.prev);
+			self.prev = now;
+			self.paused = true;
+		}
+	}
+
+	/// Resumes the clock.
+	pub fn resume(&mut self) { self.resume_at(Instant::now()) }
+
+	/// Resumes the clock at the instant given.
+	pub fn resume_at(&mut self, now: Instant) {
+		if self.paused {
+			self.prev = now;
+			self.paused = false;
+		}
+	}
+
+	/// Indicates if the clock is paused.
+	pub fn is_paused(&self) -> bool { self.paused }
+}
@@ -0,0 +1,46 @@
+//! Statistics of episodes. They are recorded by [`Ale::run_episode`](crate::Ale::run_episode), which lives with the
+//! other methods of [`Ale`](crate::Ale) in the crate root.
+
+use crate::StepResult;
+
+/// Statistics of an episode, accumulated from the result of each step.
+///
+/// # Examples
+/// ```
+/// # use ale::{Ale, BundledRom, EpisodeStats};
+/// let mut ale = Ale::new();
+/// ale.load_rom(BundledRom::Breakout).unwrap();
+/// let mut stats = EpisodeStats::new();
+/// for _ in 0..100 {
+///     let step = ale.step(1);
+///     stats.record(&step);
+///     if step.done {
+///         println!("return: {}, length: {}", stats.total_reward, stats.length);
+///         stats.reset();
+///         ale.reset_game();
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct EpisodeStats {
+	/// The sum of the rewards of every step, i.e. the return of the episode.
+	pub total_reward: i32,
+	/// The number of steps.
+	pub length:       u32,
+	/// The largest reward of a single step, or `None` if no steps have been recorded.
+	pub max_reward:   Option<i32>,
+}
+impl EpisodeStats {
+	/// Creates new statistics, with no steps recorded.
+	pub fn new() -> EpisodeStats { EpisodeStats::default() }
+
+	/// Records the result of a step.
+	pub fn record(&mut self, step: &StepResult) {
+		self.total_reward += step.reward;
+		self.length += 1;
+		self.max_reward = Some(self.max_reward.map_or(step.reward, |max| max.max(step.reward)));
+	}
+
+	/// Clears the statistics, e.g. to start recording a new episode.
+	pub fn reset(&mut self) { *self = EpisodeStats::default(); }
+}
@@ -0,0 +1,155 @@
+use std::error::Error;
+use std::fmt;
+use std::io;
+use std::time::Duration;
+
+use crate::{checksum, SettingValue};
+
+/// Errors that can be returned by the fallible parts of the [`Ale`](crate::Ale) interface.
+#[derive(Debug)]
+pub enum AleError {
+	/// The emulator could not be created by [`Ale::try_new`](crate::Ale::try_new).
+	CreationFailed,
+	/// An IO error, e.g. when writing a bundled ROM to a temporary directory.
+	Io(io::Error),
+	/// A setting did not hold the value that was applied to it after the ROM was loaded.
+	SettingMismatch {
+		/// The key of the setting.
+		key:      String,
+		/// The value that was applied.
+		expected: SettingValue,
+		/// The value that the emulator reported.
+		actual:   SettingValue,
+	},
+	/// [`Ale::load_state`](crate::Ale::load_state) was called without a state being saved first.
+	NoSavedState,
+	/// A step did not complete within the timeout given to [`Ale::act_timeout`](crate::Ale::act_timeout).
+	Timeout(Duration),
+	/// A step given to [`Ale::act_timeout`](crate::Ale::act_timeout) timed out, so the emulator was abandoned. See
+	/// [`Ale::is_poisoned`](crate::Ale::is_poisoned).
+	Poisoned,
+	/// The condition given to [`Ale::step_until`](crate::Ale::step_until) did not hold within the maximum number of
+	/// frames.
+	FrameLimitReached(u32),
+	/// The game ended while stepping, e.g. in [`Ale::step_until`](crate::Ale::step_until).
+	GameOver,
+	/// The limit set by [`Ale::set_lifetime_frame_limit`](crate::Ale::set_lifetime_frame_limit) has been reached.
+	LifetimeFrameLimitReached(u64),
+	/// The data given to [`AleState::from_versioned_bytes`](crate::AleState::from_versioned_bytes) is not a
+	/// versioned state, or the state in it is empty or truncated.
+	InvalidState,
+	/// A versioned state was saved by a different version of the ALE, so its encoding may not be compatible.
+	StateVersionMismatch {
+		/// The version of the ALE that the state was saved with.
+		saved:   String,
+		/// The version of the ALE in use.
+		current: String,
+	},
+	/// The mode given to [`Ale::try_set_mode`](crate::Ale::try_set_mode) is not available in the game.
+	InvalidMode {
+		/// The mode that was given.
+		mode:      i32,
+		/// The modes that are available.
+		available: Vec<i32>,
+	},
+	/// The difficulty given to [`Ale::try_set_difficulty`](crate::Ale::try_set_difficulty) is not available in the
+	/// game.
+	InvalidDifficulty {
+		/// The difficulty that was given.
+		difficulty: i32,
+		/// The difficulties that are available.
+		available:  Vec<i32>,
+	},
+	/// The name given when parsing a [`BundledRom`](crate::BundledRom) is not the name of a bundled ROM.
+	UnknownRom(String),
+	/// The SHA-1 checksum of a ROM did not match the checksum that was expected, so it is probably corrupted or a
+	/// different version of the game.
+	RomChecksumMismatch {
+		/// The name or path of the ROM.
+		rom:      String,
+		/// The checksum that was expected.
+		expected: [u8; 20],
+		/// The checksum of the ROM.
+		actual:   [u8; 20],
+	},
+	/// The ALE threw an exception, e.g. because a ROM could not be loaded. Contains the exception's message.
+	Emulator(String),
+	/// A method that needs a ROM was called before one was loaded. See
+	/// [`Ale::is_rom_loaded`](crate::Ale::is_rom_loaded).
+	NoRomLoaded,
+	/// The actions given to [`AleBuilder::restricted_actions`](crate::AleBuilder::restricted_actions) are not a
+	/// non-empty set of distinct legal actions.
+	InvalidActionRestriction {
+		/// The actions that were given.
+		actions: Vec<i32>,
+		/// The legal actions.
+		legal:   Vec<i32>,
+	},
+	/// An action was given that is not in the [action set](crate::Ale::action_set) the emulator was restricted to.
+	RestrictedAction(i32),
+	/// A buffer given to be written to, e.g. by [`Ale::get_screen_rgb_into`](crate::Ale::get_screen_rgb_into), is
+	/// too small.
+	BufferTooSmall {
+		/// The length that is needed.
+		required: usize,
+		/// The length of the buffer that was given.
+		provided: usize,
+	},
+}
+impl fmt::Display for AleError {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match self {
+			AleError::CreationFailed => write!(f, "failed to create emulator"),
+			AleError::Io(e) => write!(f, "IO error: {}", e),
+			AleError::SettingMismatch { key, expected, actual } => {
+				write!(f, "setting {:?} was set to {} but the emulator reports {}", key, expected, actual)
+			}
+			AleError::NoSavedState => write!(f, "no saved state to load"),
+			AleError::Timeout(timeout) => write!(f, "step timed out after {:?}", timeout),
+			AleError::Poisoned => write!(f, "the emulator was abandoned after a step timed out"),
+			AleError::FrameLimitReached(frames) => write!(f, "condition did not hold within {} frames", frames),
+			AleError::GameOver => write!(f, "the game ended"),
+			AleError::LifetimeFrameLimitReached(limit) => write!(f, "lifetime frame limit of {} reached", limit),
+			AleError::InvalidState => write!(f, "invalid versioned state"),
+			AleError::StateVersionMismatch { saved, current } => {
+				write!(f, "state was saved with ALE {}, but ALE {} is in use", saved, current)
+			}
+			AleError::InvalidMode { mode, available } => {
+				write!(f, "invalid mode {}, available modes are {:?}", mode, available)
+			}
+			AleError::InvalidDifficulty { difficulty, available } => {
+				write!(f, "invalid difficulty {}, available difficulties are {:?}", difficulty, available)
+			}
+			AleError::UnknownRom(name) => write!(f, "unknown ROM {:?}", name),
+			AleError::RomChecksumMismatch { rom, expected, actual } => write!(
+				f,
+				"checksum of ROM {} is {}, but {} was expected",
+				rom,
+				checksum::to_hex(actual),
+				checksum::to_hex(expected)
+			),
+			AleError::Emulator(message) => write!(f, "emulator error: {}", message),
+			AleError::NoRomLoaded => write!(f, "no ROM loaded"),
+			AleError::InvalidActionRestriction { actions, legal } => write!(
+				f,
+				"action restriction {:?} is not a non-empty set of distinct legal actions, which are {:?}",
+				actions, legal
+			),
+			AleError::RestrictedAction(action) => write!(f, "action {} is not in the restricted action set", action),
+			AleError::BufferTooSmall { required, provided } => {
+				write!(f, "buffer of length {} is too small, {} is required", provided, required)
+			}
+		}
+	}
+}
+impl Error for AleError {
+	fn source(&self) -> Option<&(dyn Error + 'static)> {
+		match self {
+			AleError::Io(e) => Some(e),
+			_ => None,
+		}
+	}
+}
+impl From<io::Error> for AleError {
+	fn from(e: io::Error) -> AleError { AleError::Io(e) }
+}
@@ -0,0 +1,78 @@
+//! ROMs that can't be bundled. They are loaded with [`Ale::load_extra_rom`](crate::Ale::load_extra_rom), which lives
+//! with the other methods of [`Ale`](crate::Ale) in the crate root.
+
+use crate::checksum;
+
+/// ROMs that are supported by the ALE, but can't be bundled with the library.
+///
+/// These can be loaded from a copy of the ROM with [`Ale::load_extra_rom`](crate::Ale::load_extra_rom).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ExtraRom {
+	DonkeyKong,
+	Frogger,
+	Galaxian,
+	KeystoneKapers,
+	Kingkong,
+	Koolaid,
+	LaserGates,
+	LostLuggage,
+	MrDo,
+	SirLancelot,
+	Solaris,
+	Tetris,
+	Trondead,
+	Turmoil,
+}
+impl ExtraRom {
+	/// Every extra ROM, in the order that they are declared.
+	const ALL: &'static [ExtraRom] = {
+		use ExtraRom::*;
+		&[
+			DonkeyKong,
+			Frogger,
+			Galaxian,
+			KeystoneKapers,
+			Kingkong,
+			Koolaid,
+			LaserGates,
+			LostLuggage,
+			MrDo,
+			SirLancelot,
+			Solaris,
+			Tetris,
+			Trondead,
+			Turmoil,
+		]
+	};
+
+	/// Returns every extra ROM, in the order that they are declared.
+	pub fn all() -> &'static [ExtraRom] { ExtraRom::ALL }
+
+	/// Returns the filename that the ROM should be named, in order for the ALE to pick up on it and
+	/// use the correct settings.
+	pub fn filename(&self) -> &'static str {
+		use ExtraRom::*;
+		match self {
+			DonkeyKong => "donkey_kong.bin",
+			Frogger => "frogger.bin",
+			Galaxian => "galaxian.bin",
+			KeystoneKapers => "keystone_kapers.bin",
+			Kingkong => "king_kong.bin",
+			Koolaid => "koolaid.bin",
+			LaserGates => "laser_gates.bin",
+			LostLuggage => "lost_luggage.bin",
+			MrDo => "mr_do.bin",
+			SirLancelot => "sir_lancelot.bin",
+			Solaris => "solaris.bin",
+			Tetris => "tetris.bin",
+			Trondead => "trondead.bin",
+			Turmoil => "turmoil.bin",
+		}
+	}
+
+	/// Returns the SHA-1 checksum of the ROM that the ALE supports, if it is known.
+	///
+	/// The checksums are read from the same committed table as those of the bundled ROMs (see
+	/// [`BundledRom::expected_sha1`](crate::BundledRom::expected_sha1)), under the ROM's [`ExtraRom::filename`].
+	pub fn expected_sha1(&self) -> Option<[u8; 20]> { checksum::known_sha1(self.filename()) }
+}
@@ -0,0 +1,30 @@
+//! Iteration over the frames of a game driven by a policy. The iterator is created by [`Ale::frames`], which lives
+//! with the other methods of [`Ale`] in the crate root.
+
+use crate::{Ale, StepResult};
+
+/// An iterator that drives the game with a policy, created by [`Ale::frames`].
+///
+/// Each item is the result of a step, along with the screen after the step in RGB format (see
+/// [`Ale::get_screen_rgb`]). The iterator ends when the game is over.
+pub struct FrameIter<'a, F> {
+	ale:    &'a mut Ale,
+	policy: F,
+	done:   bool,
+}
+impl<'a, F> FrameIter<'a, F> {
+	pub(crate) fn new(ale: &'a mut Ale, policy: F) -> FrameIter<'a, F> { FrameIter { ale, policy, done: false } }
+}
+impl<'a, F: FnMut(&mut Ale) -> i32> Iterator for FrameIter<'a, F> {
+	type Item = (StepResult, Vec<u8>);
+
+	fn next(&mut self) -> Option<(StepResult, Vec<u8>)> {
+		if self.done || self.ale.is_game_over() {
+			return None;
+		}
+		let action = (self.policy)(self.ale);
+		let step = self.ale.step(action);
+		self.done = step.done;
+		Some((step, self.ale.get_screen_rgb_vec()))
+	}
+}
@@ -0,0 +1,121 @@
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+
+use crate::Ale;
+
+/// A single step recorded by a [`HistoryRecorder`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HistoryStep {
+	/// The episode that the step was in, starting at `0`.
+	pub episode:   u32,
+	/// The frame number after the step, as returned by [`Ale::frame_number`].
+	pub frame:     i32,
+	/// The action that was applied.
+	pub action:    i32,
+	/// The reward that was returned by the step.
+	pub reward:    i32,
+	/// The remaining number of lives after the step.
+	pub lives:     i32,
+	/// Whether the game had ended after the step.
+	pub game_over: bool,
+}
+
+/// Records the actions and rewards of every step, so that they can be exported as CSV.
+///
+/// Steps are accumulated across episodes. Resetting the game through [`HistoryRecorder::reset_game`] starts a new
+/// episode, which is recorded in the `episode` column.
+///
+/// # Examples
+/// ```
+/// # use ale::{Ale, BundledRom, HistoryRecorder};
+/// let mut ale = Ale::new();
+/// ale.load_rom(BundledRom::Breakout).unwrap();
+/// let mut history = HistoryRecorder::new();
+/// for _ in 0..10 {
+///     history.act(&mut ale, 1);
+/// }
+/// history.reset_game(&mut ale);
+/// history.act(&mut ale, 0);
+/// assert_eq!(history.steps().len(), 11);
+/// assert_eq!(history.steps()[10].episode, 1);
+///
+/// let dir = tempdir::TempDir::new("ale-rs").unwrap();
+/// history.save_csv(dir.path().join("history.csv")).unwrap();
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct HistoryRecorder {
+	steps:   Vec<HistoryStep>,
+	episode: u32,
+}
+impl HistoryRecorder {
+	/// Creates a new, empty recorder.
+	pub fn new() -> HistoryRecorder { HistoryRecorder::default() }
+
+	/// Applies an action to the game and records the step, returning the reward.
+	pub fn act(&mut self, ale: &mut Ale, action: i32) -> i32 {
+		let reward = ale.act(action);
+		self.record(ale, action, reward);
+		reward
+	}
+
+	/// Records a step that has already been applied to the game, e.g. with [`Ale::act`].
+	///
+	/// The frame number, lives and game over flag are read from the emulator, so this should be called straight after
+	/// the step.
+	pub fn record(&mut self, ale: &mut Ale, action: i32, reward: i32) {
+		self.steps.push(HistoryStep {
+			episode: self.episode,
+			frame: ale.frame_number(),
+			action,
+			reward,
+			lives: ale.lives(),
+			game_over: ale.is_game_over(),
+		});
+	}
+
+	/// Resets the game, and starts a new episode.
+	pub fn reset_game(&mut self, ale: &mut Ale) {
+		ale.reset_game();
+		self.new_episode();
+	}
+
+	/// Starts a new episode, for when the game has been reset without going through the recorder.
+	///
+	/// This does nothing if no steps have been recorded in the current episode.
+	pub fn new_episode(&mut self) {
+		if self.steps.last().map(|step| step.episode) == Some(self.episode) {
+			self.episode += 1;
+		}
+	}
+
+	/// Returns the steps that have been recorded, in order.
+	pub fn steps(&self) -> &[HistoryStep] { &self.steps }
+
+	/// Removes all recorded steps, and starts again from episode `0`.
+	pub fn clear(&mut self) {
+		self.steps.clear();
+		self.episode = 0;
+	}
+
+	/// Writes the recorded steps as CSV, with a header row.
+	///
+	/// The columns are `episode,frame,action,reward,lives,game_over`.
+	pub fn write_csv<W: Write>(&self, mut writer: W) -> io::Result<()> {
+		writeln!(writer, "episode,frame,action,reward,lives,game_over")?;
+		for step in &self.steps {
+			writeln!(
+				writer,
+				"{},{},{},{},{},{}",
+				step.episode, step.frame, step.action, step.reward, step.lives, step.game_over
+			)?;
+		}
+		writer.flush()
+	}
+
+	/// Saves the recorded steps to a CSV file, replacing it if it already exists. See
+	/// [`HistoryRecorder::write_csv`].
+	pub fn save_csv<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+		self.write_csv(BufWriter::new(File::create(path)?))
+	}
+}
@@ -0,0 +1,44 @@
+//! Persistence of the most recently loaded bundled ROM, enabled by the `last-rom` feature.
+
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+use crate::{Ale, AleError, BundledRom};
+
+/// Returns the path of the file that the last loaded ROM is stored in.
+fn last_rom_path() -> Option<PathBuf> { dirs::config_dir().map(|dir| dir.join("ale-rs").join("last_rom")) }
+
+/// Remembers the ROM as the last loaded ROM.
+///
+/// This is best-effort: failing to write the file should not stop the ROM from being loaded.
+pub(crate) fn record(rom: BundledRom) {
+	if let Some(path) = last_rom_path() {
+		if let Some(dir) = path.parent() {
+			let _ = fs::create_dir_all(dir);
+		}
+		let _ = fs::write(&path, rom.filename());
+	}
+}
+
+impl Ale {
+	/// Creates a new emulator, and loads the bundled ROM that was most recently loaded by [`Ale::load_rom`], possibly
+	/// by a previous run of the program.
+	///
+	/// The ROM is stored in the `ale-rs` folder of the user's config directory, e.g. `~/.config/ale-rs/last_rom` on
+	/// Linux.
+	///
+	/// Returns `None` if no ROM has been loaded before, and an error if the ROM could not be loaded.
+	///
+	/// This requires the `last-rom` feature.
+	pub fn load_last_rom() -> Option<Result<Ale, AleError>> {
+		let contents = match fs::read_to_string(last_rom_path()?) {
+			Ok(contents) => contents,
+			Err(e) if e.kind() == io::ErrorKind::NotFound => return None,
+			Err(e) => return Some(Err(e.into())),
+		};
+		let rom = BundledRom::from_filename(contents.trim())?;
+		let mut ale = Ale::new();
+		Some(ale.load_rom(rom).map(|()| ale))
+	}
+}
@@ -0,0 +1,46 @@
+//! Randomized episode starts, enabled by the `rand` feature.
+
+use rand::distributions::Uniform;
+use rand::Rng;
+
+use crate::Ale;
+
+impl Ale {
+	/// Resets the game, and then applies a uniformly random number of no-op actions, from `0` to `max_noops`
+	/// inclusive.
+	///
+	/// This is the protocol commonly used to evaluate agents on Atari games, usually with `max_noops` set to `30`, so
+	/// that a deterministic agent doesn't always see the same episode. If the game ends during the no-ops, it is reset
+	/// again and the remaining no-ops are applied to the new episode, so the game is always ready to play afterwards.
+	///
+	/// Each no-op is a call to [`Ale::act`] with action `0`, so if the `frame_skip` setting is greater than one, each
+	/// no-op runs that many frames.
+	///
+	/// This requires the `rand` feature.
+	///
+	/// # Panics
+	/// If the lifetime frame limit is reached. See [`Ale::set_lifetime_frame_limit`].
+	///
+	/// # Examples
+	/// ```
+	/// # use ale::{Ale, BundledRom};
+	/// use rand::SeedableRng;
+	///
+	/// let mut ale = Ale::new();
+	/// ale.load_rom(BundledRom::Breakout).unwrap();
+	/// let mut rng = rand::rngs::StdRng::seed_from_u64(0);
+	/// ale.reset_with_noops(&mut rng, 30);
+	/// assert!(ale.episode_frame_number() <= 30);
+	/// assert!(!ale.is_game_over());
+	/// ```
+	pub fn reset_with_noops(&mut self, rng: &mut impl Rng, max_noops: u32) {
+		self.reset_game();
+		let noops = rng.sample(Uniform::new_inclusive(0, max_noops));
+		for _ in 0..noops {
+			self.try_act_unrestricted(0).unwrap_or_else(|e| panic!("{}", e));
+			if self.is_game_over() {
+				self.reset_game();
+			}
+		}
+	}
+}
@@ -0,0 +1,118 @@
+//! The Atari 2600's NTSC colour palette, for converting the raw screen returned by
+//! [`Ale::get_screen`](crate::Ale::get_screen) to RGB.
+
+/// The NTSC palette, mapping each palette index to its RGB colour.
+///
+/// The Atari ignores the lowest bit of a colour, so each odd index has the same colour as the even index before it,
+/// giving 128 distinct colours. This is the palette used by the ALE for
+/// [`Ale::get_screen_rgb`](crate::Ale::get_screen_rgb).
+#[rustfmt::skip]
+pub const NTSC_PALETTE: [[u8; 3]; 256] = [
+	// Hue 0
+	[0x00, 0x00, 0x00], [0x00, 0x00, 0x00], [0x4a, 0x4a, 0x4a], [0x4a, 0x4a, 0x4a],
+	[0x6f, 0x6f, 0x6f], [0x6f, 0x6f, 0x6f], [0x8e, 0x8e, 0x8e], [0x8e, 0x8e, 0x8e],
+	[0xaa, 0xaa, 0xaa], [0xaa, 0xaa, 0xaa], [0xc0, 0xc0, 0xc0], [0xc0, 0xc0, 0xc0],
+	[0xd6, 0xd6, 0xd6], [0xd6, 0xd6, 0xd6], [0xec, 0xec, 0xec], [0xec, 0xec, 0xec],
+	// Hue 1
+	[0x48, 0x48, 0x00], [0x48, 0x48, 0x00], [0x69, 0x69, 0x0f], [0x69, 0x69, 0x0f],
+	[0x86, 0x86, 0x1d], [0x86, 0x86, 0x1d], [0xa2, 0xa2, 0x2a], [0xa2, 0xa2, 0x2a],
+	[0xbb, 0xbb, 0x35], [0xbb, 0xbb, 0x35], [0xd2, 0xd2, 0x40], [0xd2, 0xd2, 0x40],
+	[0xe8, 0xe8, 0x4a], [0xe8, 0xe8, 0x4a], [0xfc, 0xfc, 0x54], [0xfc, 0xfc, 0x54],
+	// Hue 2
+	[0x7c, 0x2c, 0x00], [0x7c, 0x2c, 0x00], [0x90, 0x48, 0x11], [0x90, 0x48, 0x11],
+	[0xa2, 0x62, 0x21], [0xa2, 0x62, 0x21], [0xb4, 0x7a, 0x30], [0xb4, 0x7a, 0x30],
+	[0xc3, 0x90, 0x3d], [0xc3, 0x90, 0x3d], [0xd2, 0xa4, 0x4a], [0xd2, 0xa4, 0x4a],
+	[0xdf, 0xb7, 0x55], [0xdf, 0xb7, 0x55], [0xec, 0xc8, 0x60], [0xec, 0xc8, 0x60],
+	// Hue 3
+	[0x90, 0x1c, 0x00], [0x90, 0x1c, 0x00], [0xa3, 0x39, 0x15], [0xa3, 0x39, 0x15],
+	[0xb5, 0x53, 0x28], [0xb5, 0x53, 0x28], [0xc6, 0x6c, 0x3a], [0xc6, 0x6c, 0x3a],
+	[0xd5, 0x82, 0x4a], [0xd5, 0x82, 0x4a], [0xe3, 0x97, 0x59], [0xe3, 0x97, 0x59],
+	[0xf0, 0xaa, 0x67], [0xf0, 0xaa, 0x67], [0xfc, 0xbc, 0x74], [0xfc, 0xbc, 0x74],
+	// Hue 4
+	[0x94, 0x00, 0x00], [0x94, 0x00, 0x00], [0xa7, 0x1a, 0x1a], [0xa7, 0x1a, 0x1a],
+	[0xb8, 0x32, 0x32], [0xb8, 0x32, 0x32], [0xc8, 0x48, 0x48], [0xc8, 0x48, 0x48],
+	[0xd6, 0x5c, 0x5c], [0xd6, 0x5c, 0x5c], [0xe4, 0x6f, 0x6f], [0xe4, 0x6f, 0x6f],
+	[0xf0, 0x80, 0x80], [0xf0, 0x80, 0x80], [0xfc, 0x90, 0x90], [0xfc, 0x90, 0x90],
+	// Hue 5
+	[0x84, 0x00, 0x64], [0x84, 0x00, 0x64], [0x97, 0x19, 0x7a], [0x97, 0x19, 0x7a],
+	[0xa8, 0x30, 0x8f], [0xa8, 0x30, 0x8f], [0xb8, 0x46, 0xa2], [0xb8, 0x46, 0xa2],
+	[0xc6, 0x59, 0xb3], [0xc6, 0x59, 0xb3], [0xd4, 0x6c, 0xc3], [0xd4, 0x6c, 0xc3],
+	[0xe0, 0x7c, 0xd2], [0xe0, 0x7c, 0xd2], [0xec, 0x8c, 0xe0], [0xec, 0x8c, 0xe0],
+	// Hue 6
+	[0x50, 0x00, 0x84], [0x50, 0x00, 0x84], [0x68, 0x19, 0x9a], [0x68, 0x19, 0x9a],
+	[0x7d, 0x30, 0xad], [0x7d, 0x30, 0xad], [0x92, 0x46, 0xc0], [0x92, 0x46, 0xc0],
+	[0xa4, 0x59, 0xd0], [0xa4, 0x59, 0xd0], [0xb5, 0x6c, 0xe0], [0xb5, 0x6c, 0xe0],
+	[0xc5, 0x7c, 0xee], [0xc5, 0x7c, 0xee], [0xd4, 0x8c, 0xfc], [0xd4, 0x8c, 0xfc],
+	// Hue 7
+	[0x14, 0x00, 0x90], [0x14, 0x00, 0x90], [0x33, 0x1a, 0xa3], [0x33, 0x1a, 0xa3],
+	[0x4e, 0x32, 0xb5], [0x4e, 0x32, 0xb5], [0x68, 0x48, 0xc6], [0x68, 0x48, 0xc6],
+	[0x7f, 0x5c, 0xd5], [0x7f, 0x5c, 0xd5], [0x95, 0x6f, 0xe3], [0x95, 0x6f, 0xe3],
+	[0xa9, 0x80, 0xf0], [0xa9, 0x80, 0xf0], [0xbc, 0x90, 0xfc], [0xbc, 0x90, 0xfc],
+	// Hue 8
+	[0x00, 0x00, 0x94], [0x00, 0x00, 0x94], [0x18, 0x1a, 0xa7], [0x18, 0x1a, 0xa7],
+	[0x2d, 0x32, 0xb8], [0x2d, 0x32, 0xb8], [0x42, 0x48, 0xc8], [0x42, 0x48, 0xc8],
+	[0x54, 0x5c, 0xd6], [0x54, 0x5c, 0xd6], [0x65, 0x6f, 0xe4], [0x65, 0x6f, 0xe4],
+	[0x75, 0x80, 0xf0], [0x75, 0x80, 0xf0], [0x84, 0x90, 0xfc], [0x84, 0x90, 0xfc],
+	// Hue 9
+	[0x00, 0x1c, 0x88], [0x00, 0x1c, 0x88], [0x18, 0x3b, 0x9d], [0x18, 0x3b, 0x9d],
+	[0x2d, 0x57, 0xb0], [0x2d, 0x57, 0xb0], [0x42, 0x72, 0xc2], [0x42, 0x72, 0xc2],
+	[0x54, 0x8a, 0xd2], [0x54, 0x8a, 0xd2], [0x65, 0xa0, 0xe1], [0x65, 0xa0, 0xe1],
+	[0x75, 0xb5, 0xef], [0x75, 0xb5, 0xef], [0x84, 0xc8, 0xfc], [0x84, 0xc8, 0xfc],
+	// Hue 10
+	[0x00, 0x30, 0x64], [0x00, 0x30, 0x64], [0x18, 0x50, 0x80], [0x18, 0x50, 0x80],
+	[0x2d, 0x6d, 0x98], [0x2d, 0x6d, 0x98], [0x42, 0x88, 0xb0], [0x42, 0x88, 0xb0],
+	[0x54, 0xa0, 0xc5], [0x54, 0xa0, 0xc5], [0x65, 0xb7, 0xd9], [0x65, 0xb7, 0xd9],
+	[0x75, 0xcc, 0xeb], [0x75, 0xcc, 0xeb], [0x84, 0xe0, 0xfc], [0x84, 0xe0, 0xfc],
+	// Hue 11
+	[0x00, 0x40, 0x30], [0x00, 0x40, 0x30], [0x18, 0x62, 0x4e], [0x18, 0x62, 0x4e],
+	[0x2d, 0x81, 0x69], [0x2d, 0x81, 0x69], [0x42, 0x9e, 0x82], [0x42, 0x9e, 0x82],
+	[0x54, 0xb8, 0x99], [0x54, 0xb8, 0x99], [0x65, 0xd1, 0xae], [0x65, 0xd1, 0xae],
+	[0x75, 0xe7, 0xc2], [0x75, 0xe7, 0xc2], [0x84, 0xfc, 0xd4], [0x84, 0xfc, 0xd4],
+	// Hue 12
+	[0x00, 0x44, 0x00], [0x00, 0x44, 0x00], [0x1a, 0x66, 0x1a], [0x1a, 0x66, 0x1a],
+	[0x32, 0x84, 0x32], [0x32, 0x84, 0x32], [0x48, 0xa0, 0x48], [0x48, 0xa0, 0x48],
+	[0x5c, 0xba, 0x5c], [0x5c, 0xba, 0x5c], [0x6f, 0xd2, 0x6f], [0x6f, 0xd2, 0x6f],
+	[0x80, 0xe8, 0x80], [0x80, 0xe8, 0x80], [0x90, 0xfc, 0x90], [0x90, 0xfc, 0x90],
+	// Hue 13
+	[0x14, 0x3c, 0x00], [0x14, 0x3c, 0x00], [0x35, 0x5f, 0x18], [0x35, 0x5f, 0x18],
+	[0x52, 0x7e, 0x2d], [0x52, 0x7e, 0x2d], [0x6e, 0x9c, 0x42], [0x6e, 0x9c, 0x42],
+	[0x87, 0xb7, 0x54], [0x87, 0xb7, 0x54], [0x9e, 0xd0, 0x65], [0x9e, 0xd0, 0x65],
+	[0xb4, 0xe7, 0x75], [0xb4, 0xe7, 0x75], [0xc8, 0xfc, 0x84], [0xc8, 0xfc, 0x84],
+	// Hue 14
+	[0x30, 0x38, 0x00], [0x30, 0x38, 0x00], [0x50, 0x59, 0x16], [0x50, 0x59, 0x16],
+	[0x6d, 0x76, 0x2b], [0x6d, 0x76, 0x2b], [0x88, 0x92, 0x3e], [0x88, 0x92, 0x3e],
+	[0xa0, 0xab, 0x4f], [0xa0, 0xab, 0x4f], [0xb7, 0xc2, 0x5f], [0xb7, 0xc2, 0x5f],
+	[0xcc, 0xd8, 0x6e], [0xcc, 0xd8, 0x6e], [0xe0, 0xec, 0x7c], [0xe0, 0xec, 0x7c],
+	// Hue 15
+	[0x48, 0x2c, 0x00], [0x48, 0x2c, 0x00], [0x69, 0x4d, 0x14], [0x69, 0x4d, 0x14],
+	[0x86, 0x6a, 0x26], [0x86, 0x6a, 0x26], [0xa2, 0x86, 0x38], [0xa2, 0x86, 0x38],
+	[0xbb, 0x9f, 0x47], [0xbb, 0x9f, 0x47], [0xd2, 0xb6, 0x56], [0xd2, 0xb6, 0x56],
+	[0xe8, 0xcc, 0x63], [0xe8, 0xcc, 0x63], [0xfc, 0xe0, 0x70], [0xfc, 0xe0, 0x70],
+];
+
+/// Returns the RGB colour of a palette index.
+pub fn index_to_rgb(index: u8) -> [u8; 3] { NTSC_PALETTE[index as usize] }
+
+/// Converts a screen of palette indices, as returned by [`Ale::get_screen`](crate::Ale::get_screen), to RGB
+/// format, as returned by [`Ale::get_screen_rgb`](crate::Ale::get_screen_rgb).
+///
+/// # Panics
+/// If `out` is smaller than `indexed.len() * 3`.
+///
+/// # Examples
+/// ```
+/// # use ale::{palette, Ale, BundledRom};
+/// let mut ale = Ale::new();
+/// ale.load_rom(BundledRom::Breakout).unwrap();
+/// let mut indexed = vec![0; ale.screen_spec().indexed_len];
+/// ale.get_screen(&mut indexed);
+/// let mut rgb = vec![0; indexed.len() * 3];
+/// palette::map_indexed_to_rgb(&indexed, &mut rgb);
+/// assert_eq!(rgb, ale.get_screen_rgb_vec());
+/// ```
+pub fn map_indexed_to_rgb(indexed: &[u8], out: &mut [u8]) {
+	assert!(out.len() >= indexed.len() * 3, "Output buffer is too small");
+	for (&index, rgb) in indexed.iter().zip(out.chunks_exact_mut(3)) {
+		rgb.copy_from_slice(&NTSC_PALETTE[index as usize]);
+	}
+}
@@ -0,0 +1,117 @@
+//! Preprocessing of observations, as commonly used when training agents.
+
+use crate::Ale;
+
+/// Takes the element-wise maximum of the last two grayscale frames.
+///
+/// Many Atari games draw some sprites only on alternate frames, which makes them flicker. Taking the maximum of two
+/// consecutive frames makes every sprite visible in every observation.
+///
+/// # Examples
+/// ```
+/// # use ale::{Ale, BundledRom};
+/// # use ale::preprocess::FrameMaxPool;
+/// let mut ale = Ale::new();
+/// ale.load_rom(BundledRom::Breakout).unwrap();
+/// let mut max_pool = FrameMaxPool::new();
+/// let observation = max_pool.step(&mut ale, 1);
+/// assert_eq!(observation.len(), 160 * 210);
+///
+/// // Frames can also be given directly
+/// let mut max_pool = FrameMaxPool::new();
+/// assert_eq!(max_pool.pool(vec![1, 5, 3]), vec![1, 5, 3]);
+/// assert_eq!(max_pool.pool(vec![4, 2, 3]), vec![4, 5, 3]);
+/// assert_eq!(max_pool.pool(vec![0, 0, 9]), vec![4, 2, 9]);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct FrameMaxPool {
+	prev: Option<Vec<u8>>,
+}
+impl FrameMaxPool {
+	/// Creates a new max pool, with no previous frame.
+	pub fn new() -> FrameMaxPool { FrameMaxPool::default() }
+
+	/// Applies an action to the game, and returns the maximum of the grayscale screen and the previous frame.
+	///
+	/// On the first call there is no previous frame, so the screen is returned as is.
+	pub fn step(&mut self, ale: &mut Ale, action: i32) -> Vec<u8> {
+		ale.act(action);
+		self.pool(ale.get_screen_grayscale_vec())
+	}
+
+	/// Returns the element-wise maximum of the frame given and the previous frame, and keeps the frame for the next
+	/// call.
+	///
+	/// If there is no previous frame, or it is a different size, the frame is returned as is.
+	pub fn pool(&mut self, frame: Vec<u8>) -> Vec<u8> {
+		let pooled = match &self.prev {
+			Some(prev) if prev.len() == frame.len() => prev.iter().zip(frame.iter()).map(|(&a, &b)| a.max(b)).collect(),
+			_ => frame.clone(),
+		};
+		self.prev = Some(frame);
+		pooled
+	}
+
+	/// Forgets the previous frame, e.g. when the game is reset.
+	pub fn reset(&mut self) { self.prev = None; }
+}
+
+/// Stacks the last few grayscale frames into a single observation, so that agents can see motion.
+///
+/// Frames are resized to the size given (see [`Ale::get_screen_grayscale_resized`]), and laid out contiguously from
+/// the oldest to the newest, i.e. `[frame0, frame1, ...]`.
+///
+/// # Examples
+/// ```
+/// # use ale::{Ale, BundledRom};
+/// # use ale::preprocess::FrameStack;
+/// let mut ale = Ale::new();
+/// ale.load_rom(BundledRom::Breakout).unwrap();
+/// // The classic DQN observation: the last 4 frames at 84x84
+/// let mut stack = FrameStack::new(4, 84, 84);
+/// stack.reset(&mut ale);
+/// let observation = stack.step(&mut ale, 1);
+/// assert_eq!(observation.len(), 4 * 84 * 84);
+/// ```
+#[derive(Debug, Clone)]
+pub struct FrameStack {
+	depth:  usize,
+	width:  usize,
+	height: usize,
+	frames: Vec<u8>,
+}
+impl FrameStack {
+	/// Creates a new frame stack, holding `depth` frames of `width` by `height` pixels.
+	///
+	/// # Panics
+	/// If `depth`, `width` or `height` is `0`.
+	pub fn new(depth: usize, width: usize, height: usize) -> FrameStack {
+		assert!(depth > 0, "Depth must be non-zero");
+		assert!(width > 0 && height > 0, "Size must be non-zero");
+		FrameStack { depth, width, height, frames: vec![0; depth * width * height] }
+	}
+
+	/// Resets the game, and fills the stack with the initial frame.
+	pub fn reset<'a>(&'a mut self, ale: &mut Ale) -> &'a [u8] {
+		ale.reset_game();
+		let frame_len = self.width * self.height;
+		ale.get_screen_grayscale_resized(self.width, self.height, &mut self.frames[..frame_len]);
+		for i in 1..self.depth {
+			self.frames.copy_within(..frame_len, i * frame_len);
+		}
+		&self.frames
+	}
+
+	/// Applies an action to the game, and pushes the new frame onto the stack, dropping the oldest frame.
+	pub fn step<'a>(&'a mut self, ale: &mut Ale, action: i32) -> &'a [u8] {
+		ale.act(action);
+		let frame_len = self.width * self.height;
+		self.frames.copy_within(frame_len.., 0);
+		let newest = self.frames.len() - frame_len;
+		ale.get_screen_grayscale_resized(self.width, self.height, &mut self.frames[newest..]);
+		&self.frames
+	}
+
+	/// Returns the stacked frames, from the oldest to the newest.
+	pub fn frames(&self) -> &[u8] { &self.frames }
+}
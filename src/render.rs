@@ -0,0 +1,81 @@
+//! Rendering of screens, for watching and debugging games. The rendering methods of [`Ale`] live with its other
+//! methods in the crate root, so this module only holds the helpers that they use.
+
+use std::env;
+
+use crate::Ale;
+
+/// The terminal size that is assumed if it can't be detected.
+const DEFAULT_TERMINAL_SIZE: (usize, usize) = (80, 24);
+
+/// Returns the size of the terminal in `(columns, rows)`.
+///
+/// The size is queried from the terminal that stdout is attached to. If stdout isn't a terminal, e.g. because it is
+/// piped, it is taken from the `COLUMNS` and `LINES` environment variables instead.
+pub(crate) fn terminal_size() -> (usize, usize) {
+	if let Some((terminal_size::Width(columns), terminal_size::Height(rows))) = terminal_size::terminal_size() {
+		if columns > 0 && rows > 0 {
+			return (columns as usize, rows as usize);
+		}
+	}
+	let var = |key| env::var(key).ok().and_then(|v| v.trim().parse::<usize>().ok()).filter(|&v| v > 0);
+	(var("COLUMNS").unwrap_or(DEFAULT_TERMINAL_SIZE.0), var("LINES").unwrap_or(DEFAULT_TERMINAL_SIZE.1))
+}
+
+/// Renders the screens of two emulators next to each other, for comparing them, e.g. when debugging why two
+/// environments have diverged.
+///
+/// Returns the combined screen in RGB format, along with its width and height. `a` is on the left and `b` is on the
+/// right. If the screens are different heights, the shorter one is padded with black.
+///
+/// If `highlight_differences` is set, pixels that differ between the two screens are drawn in magenta on both sides.
+///
+/// # Examples
+/// ```
+/// # use ale::{Ale, BundledRom};
+/// let mut a = Ale::new();
+/// a.load_rom(BundledRom::Breakout).unwrap();
+/// let mut b = Ale::new();
+/// b.load_rom(BundledRom::Breakout).unwrap();
+/// b.act(1);
+/// let (screen, width, height) = ale::render_side_by_side(&mut a, &mut b, true);
+/// assert_eq!((width, height), (320, 210));
+/// assert_eq!(screen.len(), width * height * 3);
+/// ```
+pub fn render_side_by_side(a: &mut Ale, b: &mut Ale, highlight_differences: bool) -> (Vec<u8>, usize, usize) {
+	const HIGHLIGHT: [u8; 3] = [255, 0, 255];
+
+	let (a_width, a_height) = (a.screen_width(), a.screen_height());
+	let (b_width, b_height) = (b.screen_width(), b.screen_height());
+	let a_screen = a.get_screen_rgb_vec();
+	let b_screen = b.get_screen_rgb_vec();
+
+	let width = a_width + b_width;
+	let height = a_height.max(b_height);
+	let mut screen = vec![0; width * height * 3];
+	for y in 0..height {
+		let row = &mut screen[y * width * 3..(y + 1) * width * 3];
+		if y < a_height {
+			row[..a_width * 3].copy_from_slice(&a_screen[y * a_width * 3..(y + 1) * a_width * 3]);
+		}
+		if y < b_height {
+			row[a_width * 3..].copy_from_slice(&b_screen[y * b_width * 3..(y + 1) * b_width * 3]);
+		}
+	}
+
+	if highlight_differences {
+		for y in 0..a_height.min(b_height) {
+			for x in 0..a_width.min(b_width) {
+				let a_i = (y * a_width + x) * 3;
+				let b_i = (y * b_width + x) * 3;
+				if a_screen[a_i..a_i + 3] != b_screen[b_i..b_i + 3] {
+					let i = (y * width + x) * 3;
+					screen[i..i + 3].copy_from_slice(&HIGHLIGHT);
+					let i = i + a_width * 3;
+					screen[i..i + 3].copy_from_slice(&HIGHLIGHT);
+				}
+			}
+		}
+	}
+	(screen, width, height)
+}
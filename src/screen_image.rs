@@ -0,0 +1,34 @@
+//! Conversion of the screen to images from the `image` crate, enabled by the `image` feature.
+
+use image::{GrayImage, RgbImage};
+
+use crate::Ale;
+
+impl Ale {
+	/// Returns the screen as an RGB image.
+	///
+	/// This requires the `image` feature.
+	///
+	/// # Examples
+	/// ```
+	/// # use ale::{Ale, BundledRom};
+	/// let mut ale = Ale::new();
+	/// ale.load_rom(BundledRom::Breakout).unwrap();
+	/// let image = ale.screen_rgb_image();
+	/// assert_eq!(image.dimensions(), (160, 210));
+	/// ```
+	pub fn screen_rgb_image(&mut self) -> RgbImage {
+		let (width, height) = (self.screen_width(), self.screen_height());
+		RgbImage::from_raw(width as u32, height as u32, self.get_screen_rgb_vec())
+			.expect("screen buffer does not match the screen size")
+	}
+
+	/// Returns the screen as a grayscale image.
+	///
+	/// This requires the `image` feature.
+	pub fn screen_grayscale_image(&mut self) -> GrayImage {
+		let (width, height) = (self.screen_width(), self.screen_height());
+		GrayImage::from_raw(width as u32, height as u32, self.get_screen_grayscale_vec())
+			.expect("screen buffer does not match the screen size")
+	}
+}
@@ -0,0 +1,39 @@
+//! Observations as arrays from the `ndarray` crate, enabled by the `ndarray` feature.
+
+use ndarray::{Array1, Array2, Array3};
+
+use crate::Ale;
+
+impl Ale {
+	/// Returns the screen in RGB format, as an array shaped `(height, width, 3)`.
+	///
+	/// This requires the `ndarray` feature.
+	///
+	/// # Examples
+	/// ```
+	/// # use ale::{Ale, BundledRom};
+	/// let mut ale = Ale::new();
+	/// ale.load_rom(BundledRom::Breakout).unwrap();
+	/// let screen = ale.screen_rgb_ndarray();
+	/// assert_eq!(screen.shape(), &[210, 160, 3]);
+	/// ```
+	pub fn screen_rgb_ndarray(&mut self) -> Array3<u8> {
+		let (width, height) = (self.screen_width(), self.screen_height());
+		Array3::from_shape_vec((height, width, 3), self.get_screen_rgb_vec())
+			.expect("screen buffer does not match the screen size")
+	}
+
+	/// Returns the screen in grayscale format, as an array shaped `(height, width)`.
+	///
+	/// This requires the `ndarray` feature.
+	pub fn screen_grayscale_ndarray(&mut self) -> Array2<u8> {
+		let (width, height) = (self.screen_width(), self.screen_height());
+		Array2::from_shape_vec((height, width), self.get_screen_grayscale_vec())
+			.expect("screen buffer does not match the screen size")
+	}
+
+	/// Returns the emulator's RAM contents, as an array.
+	///
+	/// This requires the `ndarray` feature.
+	pub fn ram_ndarray(&mut self) -> Array1<u8> { Array1::from(self.get_ram_vec()) }
+}
@@ -0,0 +1,119 @@
+use std::collections::btree_map::{self, BTreeMap};
+use std::fmt;
+
+use crate::{Ale, BundledRom};
+
+/// The value of a single ALE setting.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SettingValue {
+	String(String),
+	Int(i32),
+	Bool(bool),
+	Float(f32),
+}
+impl fmt::Display for SettingValue {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match self {
+			SettingValue::String(v) => write!(f, "{:?}", v),
+			SettingValue::Int(v) => write!(f, "{}", v),
+			SettingValue::Bool(v) => write!(f, "{}", v),
+			SettingValue::Float(v) => write!(f, "{}", v),
+		}
+	}
+}
+
+/// A collection of ALE settings, keyed by the setting name.
+///
+/// Settings only take effect when a ROM is loaded, so they should be applied with [`Settings::apply`]
+/// before calling [`Ale::load_rom`].
+///
+/// # Examples
+/// ```
+/// # use ale::Settings;
+/// let mut settings = Settings::new();
+/// settings.set_int("frame_skip", 4).set_float("repeat_action_probability", 0.25);
+/// assert_eq!(settings.len(), 2);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Settings {
+	values: BTreeMap<String, SettingValue>,
+}
+impl Settings {
+	/// Creates an empty collection of settings.
+	pub fn new() -> Settings { Settings::default() }
+
+	/// Sets the value of a string setting.
+	pub fn set_string(&mut self, key: &str, value: &str) -> &mut Settings {
+		self.set(key, SettingValue::String(value.to_owned()))
+	}
+
+	/// Sets the value of an integer setting.
+	pub fn set_int(&mut self, key: &str, value: i32) -> &mut Settings { self.set(key, SettingValue::Int(value)) }
+
+	/// Sets the value of a bool setting.
+	pub fn set_bool(&mut self, key: &str, value: bool) -> &mut Settings { self.set(key, SettingValue::Bool(value)) }
+
+	/// Sets the value of a float setting.
+	pub fn set_float(&mut self, key: &str, value: f32) -> &mut Settings { self.set(key, SettingValue::Float(value)) }
+
+	/// Sets the value of a setting, replacing any previous value.
+	pub fn set(&mut self, key: &str, value: SettingValue) -> &mut Settings {
+		self.values.insert(key.to_owned(), value);
+		self
+	}
+
+	/// Returns the value of a setting, if it has been set.
+	pub fn get(&self, key: &str) -> Option<&SettingValue> { self.values.get(key) }
+
+	/// Returns the number of settings in the collection.
+	pub fn len(&self) -> usize { self.values.len() }
+
+	/// Returns `true` if no settings have been set.
+	pub fn is_empty(&self) -> bool { self.values.is_empty() }
+
+	/// Iterates over the settings, ordered by key.
+	pub fn iter(&self) -> btree_map::Iter<'_, String, SettingValue> { self.values.iter() }
+
+	/// Applies every setting to the emulator.
+	///
+	/// This should be called before the ROM is loaded for the settings to take effect.
+	pub fn apply(&self, ale: &mut Ale) {
+		for (key, value) in self.iter() {
+			match value {
+				SettingValue::String(v) => ale.set_string(key, v),
+				SettingValue::Int(v) => ale.set_int(key, *v),
+				SettingValue::Bool(v) => ale.set_bool(key, *v),
+				SettingValue::Float(v) => ale.set_float(key, *v),
+			}
+		}
+	}
+}
+impl<'a> IntoIterator for &'a Settings {
+	type IntoIter = btree_map::Iter<'a, String, SettingValue>;
+	type Item = (&'a String, &'a SettingValue);
+
+	fn into_iter(self) -> Self::IntoIter { self.iter() }
+}
+
+/// Report on a configuration, produced by [`Ale::validate_config`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConfigReport {
+	/// The ROM that was loaded.
+	pub rom: BundledRom,
+	/// The number of legal actions.
+	pub legal_action_count: usize,
+	/// The minimal set of actions needed to play the game.
+	pub minimal_action_set: Vec<i32>,
+	/// The screen's width in pixels.
+	pub screen_width: usize,
+	/// The screen's height in pixels.
+	pub screen_height: usize,
+	/// The modes available for the game.
+	pub available_modes: Vec<i32>,
+	/// The difficulties available for the game.
+	pub available_difficulties: Vec<i32>,
+	/// The settings as reported by the emulator after the ROM was loaded.
+	///
+	/// String settings are reported as they were applied, as they cannot be read back.
+	pub settings: Settings,
+}
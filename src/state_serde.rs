@@ -0,0 +1,67 @@
+//! Serialization of states with `serde`, enabled by the `serde` feature.
+
+use std::convert::TryFrom;
+use std::fmt;
+use std::os::raw::c_int;
+
+use serde::de::{self, Deserialize, Deserializer, SeqAccess, Visitor};
+use serde::ser::{Serialize, Serializer};
+
+use crate::AleState;
+
+/// Serializes the state as bytes, as encoded by [`AleState::encode_state`].
+///
+/// This requires the `serde` feature.
+///
+/// # Examples
+/// ```
+/// # use ale::{Ale, AleState, BundledRom};
+/// let mut ale = Ale::new();
+/// ale.load_rom(BundledRom::Breakout).unwrap();
+/// let json = serde_json::to_string(&ale.clone_state()).unwrap();
+///
+/// let state: AleState = serde_json::from_str(&json).unwrap();
+/// ale.restore_state(&state);
+///
+/// assert!(serde_json::from_str::<AleState>("[]").is_err());
+/// ```
+impl Serialize for AleState {
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		serializer.serialize_bytes(&self.to_bytes())
+	}
+}
+
+/// Deserializes a state from bytes, decoding it with [`AleState::decode_state`].
+///
+/// Empty buffers, and buffers too long to be passed to the ALE, are rejected with an error. Any other bytes are passed
+/// to the ALE as they are, so they should come from a serialized state.
+impl<'de> Deserialize<'de> for AleState {
+	fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<AleState, D::Error> {
+		deserializer.deserialize_byte_buf(StateVisitor)
+	}
+}
+
+struct StateVisitor;
+impl StateVisitor {
+	fn decode<E: de::Error>(&self, bytes: &[u8]) -> Result<AleState, E> {
+		if bytes.is_empty() || c_int::try_from(bytes.len()).is_err() {
+			return Err(E::invalid_length(bytes.len(), self));
+		}
+		Ok(AleState::decode_state(bytes))
+	}
+}
+impl<'de> Visitor<'de> for StateVisitor {
+	type Value = AleState;
+
+	fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result { write!(f, "a non-empty encoded ALE state") }
+
+	fn visit_bytes<E: de::Error>(self, bytes: &[u8]) -> Result<AleState, E> { self.decode(bytes) }
+
+	fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<AleState, A::Error> {
+		let mut bytes = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+		while let Some(byte) = seq.next_element()? {
+			bytes.push(byte);
+		}
+		self.decode(&bytes)
+	}
+}
@@ -0,0 +1,77 @@
+use crate::Ale;
+
+/// The result of a step taken through a [`TimeLimit`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TimeLimitStep {
+	/// The reward that was returned by the step.
+	pub reward:     i32,
+	/// Whether the step ended the game, i.e. the episode reached a terminal state.
+	pub terminated: bool,
+	/// Whether the episode was cut short by the time limit without reaching a terminal state.
+	pub truncated:  bool,
+}
+
+/// Limits the number of frames in an episode, and reports reaching the limit separately from the game ending.
+///
+/// Reinforcement learning algorithms need to tell these apart, as the value of the state after a truncated episode
+/// should still be bootstrapped. This is the termination and truncation split of Gymnasium. The
+/// `max_num_frames_per_episode` setting can't be used for this, as the ALE reports reaching it as the game ending.
+///
+/// Frames are counted as they are run, so if the `frame_skip` setting is greater than one, each step counts as that
+/// many frames. The count is reset by [`TimeLimit::reset`], and when the game is automatically reset (see
+/// [`Ale::set_auto_reset`]).
+///
+/// # Examples
+/// ```
+/// # use ale::{Ale, BundledRom, TimeLimit};
+/// let mut ale = Ale::new();
+/// ale.load_rom(BundledRom::Breakout).unwrap();
+/// let mut time_limit = TimeLimit::new(100);
+/// time_limit.reset(&mut ale);
+/// loop {
+///     let step = time_limit.step(&mut ale, 1);
+///     if step.terminated || step.truncated {
+///         assert!(step.truncated);
+///         break;
+///     }
+/// }
+/// assert_eq!(time_limit.frames(), 100);
+/// ```
+#[derive(Debug, Clone)]
+pub struct TimeLimit {
+	max_frames: u64,
+	frames:     u64,
+}
+impl TimeLimit {
+	/// Creates a limit of `max_frames` frames per episode.
+	pub fn new(max_frames: u64) -> TimeLimit { TimeLimit { max_frames, frames: 0 } }
+
+	/// Returns the number of frames that have been run in the current episode.
+	pub fn frames(&self) -> u64 { self.frames }
+
+	/// Returns the maximum number of frames per episode.
+	pub fn max_frames(&self) -> u64 { self.max_frames }
+
+	/// Resets the game, and the count of frames.
+	pub fn reset(&mut self, ale: &mut Ale) {
+		ale.reset_game();
+		self.frames = 0;
+	}
+
+	/// Applies an action to the game, and returns the reward along with whether the episode has ended.
+	///
+	/// Once the limit is reached, every step is reported as truncated until [`TimeLimit::reset`] is called.
+	///
+	/// # Panics
+	/// If the lifetime frame limit has been reached. See [`Ale::set_lifetime_frame_limit`].
+	pub fn step(&mut self, ale: &mut Ale, action: i32) -> TimeLimitStep {
+		let lifetime_frames = ale.lifetime_frames();
+		let step = ale.step(action);
+		self.frames += ale.lifetime_frames() - lifetime_frames;
+		let truncated = !step.done && self.frames >= self.max_frames;
+		if ale.did_auto_reset() {
+			self.frames = 0;
+		}
+		TimeLimitStep { reward: step.reward, terminated: step.done, truncated }
+	}
+}
@@ -0,0 +1,111 @@
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+use crate::{Ale, StepResult};
+
+/// A batch of emulators that are stepped together, like the vectorized environments used to train agents.
+///
+/// Each call to [`VecAle::step_batch`] applies an action to every emulator, and resets any emulator whose game ended,
+/// so the batch is always ready for the next step. With the `rayon` feature enabled, the emulators are stepped in
+/// parallel on the `rayon` thread pool.
+///
+/// # Examples
+/// ```
+/// # use ale::{Ale, BundledRom, VecAle};
+/// let ales = (0..4)
+///     .map(|_| {
+///         let mut ale = Ale::new();
+///         ale.load_rom(BundledRom::Breakout).unwrap();
+///         ale
+///     })
+///     .collect();
+/// let mut envs = VecAle::new(ales);
+/// envs.reset_all();
+/// for _ in 0..100 {
+///     let results = envs.step_batch(&[1, 3, 4, 0]);
+///     assert_eq!(results.len(), 4);
+/// }
+/// let screens = envs.get_screens_grayscale();
+/// assert_eq!(screens.len(), 4 * 160 * 210);
+/// ```
+#[derive(Debug)]
+pub struct VecAle {
+	ales: Vec<Ale>,
+}
+impl VecAle {
+	/// Creates a batch from the emulators given, which should have their ROMs loaded.
+	pub fn new(ales: Vec<Ale>) -> VecAle { VecAle { ales } }
+
+	/// Returns the number of emulators in the batch.
+	pub fn len(&self) -> usize { self.ales.len() }
+
+	/// Indicates if the batch has no emulators.
+	pub fn is_empty(&self) -> bool { self.ales.is_empty() }
+
+	/// Returns the emulators in the batch.
+	pub fn ales(&self) -> &[Ale] { &self.ales }
+
+	/// Returns the emulators in the batch mutably, e.g. to change their settings.
+	pub fn ales_mut(&mut self) -> &mut [Ale] { &mut self.ales }
+
+	/// Returns the emulators in the batch.
+	pub fn into_inner(self) -> Vec<Ale> { self.ales }
+
+	/// Resets the game of every emulator.
+	pub fn reset_all(&mut self) {
+		for ale in &mut self.ales {
+			ale.reset_game();
+		}
+	}
+
+	/// Applies an action to each emulator, and returns the result of each step, in order.
+	///
+	/// If a step ends the game, `done` is set in its result, and the game is reset so that the emulator is ready for
+	/// the next step.
+	///
+	/// # Panics
+	/// If the number of actions is not the number of emulators, or if the lifetime frame limit of an emulator has
+	/// been reached (see [`Ale::set_lifetime_frame_limit`]).
+	pub fn step_batch(&mut self, actions: &[i32]) -> Vec<StepResult> {
+		assert_eq!(actions.len(), self.ales.len(), "expected an action for each emulator");
+		#[cfg(feature = "rayon")]
+		let ales = self.ales.par_iter_mut().zip(actions.par_iter());
+		#[cfg(not(feature = "rayon"))]
+		let ales = self.ales.iter_mut().zip(actions.iter());
+		ales.map(|(ale, &action)| step_and_reset(ale, action)).collect()
+	}
+
+	/// Returns the screens of every emulator in RGB format, one after the other in a single buffer.
+	///
+	/// See [`Ale::get_screen_rgb`].
+	pub fn get_screens_rgb(&mut self) -> Vec<u8> {
+		let mut screens = vec![];
+		for ale in &mut self.ales {
+			screens.extend_from_slice(&ale.get_screen_rgb_vec());
+		}
+		screens
+	}
+
+	/// Returns the screens of every emulator in grayscale format, one after the other in a single buffer.
+	///
+	/// See [`Ale::get_screen_grayscale`].
+	pub fn get_screens_grayscale(&mut self) -> Vec<u8> {
+		let mut screens = vec![];
+		for ale in &mut self.ales {
+			screens.extend_from_slice(&ale.get_screen_grayscale_vec());
+		}
+		screens
+	}
+}
+impl From<Vec<Ale>> for VecAle {
+	fn from(ales: Vec<Ale>) -> VecAle { VecAle::new(ales) }
+}
+
+/// Steps an emulator, and resets it if the step ended the game.
+fn step_and_reset(ale: &mut Ale, action: i32) -> StepResult {
+	let result = ale.step(action);
+	if result.done && !ale.did_auto_reset() {
+		ale.reset_game();
+	}
+	result
+}
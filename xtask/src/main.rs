@@ -1,6 +1,7 @@
 
 use bindgen::CodegenConfig;
 
+use std::collections::HashMap;
 use std::fs::File;
 use std::path::{Path, PathBuf};
 use std::ffi::OsStr;
@@ -8,11 +9,24 @@ use std::ffi::OsStr;
 use clap::{Arg, App, SubCommand};
 
 
-const PIP_ATARI_PY_TAR_URL: &'static str = "https://files.pythonhosted.org/packages/43/dd/2721f34a89dc520d2e09363fd23d110a33bbab2399e50fdced6eb2ed2157/atari-py-0.2.6.tar.gz";
+/// URLs of the atari-py source distribution, which are tried in order. The PyPI mirrors use the same paths as PyPI.
+const ATARI_PY_TAR_URLS: &'static [&'static str] = &[
+	"https://files.pythonhosted.org/packages/43/dd/2721f34a89dc520d2e09363fd23d110a33bbab2399e50fdced6eb2ed2157/atari-py-0.2.6.tar.gz",
+	"https://pypi.tuna.tsinghua.edu.cn/packages/43/dd/2721f34a89dc520d2e09363fd23d110a33bbab2399e50fdced6eb2ed2157/atari-py-0.2.6.tar.gz",
+	"https://mirrors.aliyun.com/pypi/packages/43/dd/2721f34a89dc520d2e09363fd23d110a33bbab2399e50fdced6eb2ed2157/atari-py-0.2.6.tar.gz",
+];
+const ATARI_PY_TAR_URL_ENV: &'static str = "ALE_ROMS_URL";
 const ATARI_PY_TAR_FILENAME: &'static str = "atari-py-0.2.6.tar.gz";
+/// The BLAKE2b-256 digest of the atari-py source distribution, which PyPI publishes as the path of the file. This is
+/// what the ROMs are trusted by, whichever URL they are downloaded from.
+const ATARI_PY_TAR_BLAKE2B_256: &'static str = "43dd2721f34a89dc520d2e09363fd23d110a33bbab2399e50fdced6eb2ed2157";
+
+/// The known-good checksums of the ROMs, which the `ale` crate checks ROMs against before loading them.
+const ROM_SHA1SUMS_PATH: &'static str = "src/rom_sha1sums.txt";
 
 const XTASK_PREFIX: &'static str = "\x1B[1m\x1B[32m       xtask\x1B[0m ";
 const ERROR_PREFIX: &'static str = "\x1B[1m\x1B[31merror\x1B[37m:\x1B[0m ";
+const WARNING_PREFIX: &'static str = "\x1B[1m\x1B[33mwarning\x1B[37m:\x1B[0m ";
 
 fn main() {
 	let mut app = App::new("ale-xtask")
@@ -22,7 +36,18 @@ fn main() {
 		.subcommand(SubCommand::with_name("gen-bindings")
 			.about("Generate Arcade Learning Environment bindings"))
 		.subcommand(SubCommand::with_name("download-roms")
-			.about("Download builtin Atari ROMs, and place in the roms/ folder"))
+			.about("Download builtin Atari ROMs, and place in the roms/ folder")
+			.arg(Arg::with_name("url")
+				.long("url")
+				.takes_value(true)
+				.value_name("URL")
+				.help("Download atari-py 0.2.6 from this URL instead of PyPI and its mirrors. Defaults to the ALE_ROMS_URL \
+					environment variable, if set"))
+			.arg(Arg::with_name("record-checksums")
+				.long("record-checksums")
+				.help("Record the checksums of the downloaded ROMs in src/rom_sha1sums.txt, instead of verifying them")))
+		.subcommand(SubCommand::with_name("verify-roms")
+			.about("Check that the roms/ folder has every ROM that is bundled, with the expected checksums"))
 		.subcommand(SubCommand::with_name("clean")
 			.about("Remove the target directories")
 			.arg(Arg::with_name("all")
@@ -35,9 +60,14 @@ fn main() {
 		eprintln!("{}gen-bindings", XTASK_PREFIX);
 		run_bindgen();
 
-	} else if let Some(_) = matches.subcommand_matches("download-roms") {
+	} else if let Some(matches) = matches.subcommand_matches("download-roms") {
 		eprintln!("{}download-roms", XTASK_PREFIX);
-		run_download_roms();
+		let url = matches.value_of("url").map(str::to_owned).or_else(|| std::env::var(ATARI_PY_TAR_URL_ENV).ok());
+		run_download_roms(url.as_ref().map(String::as_str), matches.is_present("record-checksums"));
+
+	} else if let Some(_) = matches.subcommand_matches("verify-roms") {
+		eprintln!("{}verify-roms", XTASK_PREFIX);
+		run_verify_bundled_roms();
 
 	} else if let Some(matches) = matches.subcommand_matches("clean") {
 		eprintln!("{}clean", XTASK_PREFIX);
@@ -77,6 +107,11 @@ fn run_bindgen() {
 		.whitelist_function("setFloat")
 		.whitelist_function("loadROM")
 		.whitelist_function("act")
+		.whitelist_function("act2")
+		.whitelist_function("act2Supported")
+		.whitelist_function("tryLoadROM")
+		.whitelist_function("trySaveScreenPNG")
+		.whitelist_function("getStringInto")
 		.whitelist_function("game_over")
 		.whitelist_function("reset_game")
 		.whitelist_function("getAvailableModes")
@@ -111,8 +146,12 @@ fn run_bindgen() {
 		.whitelist_function("encodeStateLen")
 		.whitelist_function("decodeState")
 		.whitelist_function("setLoggerMode")
+		.whitelist_function("setLoggerSilent")
 		.opaque_type(".*")
+		.size_t_is_usize(true)
 		.with_codegen_config(CodegenConfig::FUNCTIONS | CodegenConfig::TYPES)
+		// Format with the repository's settings, so that the committed bindings can be checked by regenerating them
+		.rustfmt_configuration_file(Some(project_root().join(".rustfmt.toml")))
 		.generate() {
 			Ok(b) => b,
 			Err(e) => {
@@ -127,44 +166,230 @@ fn run_bindgen() {
 	}
 }
 
-fn run_download_roms() {
+fn run_download_roms(url: Option<&str>, record_checksums: bool) {
 	let dir = tempdir::TempDir::new("ale-xtask").expect("failed to generate temp directory");
 	let tar_path = dir.path().join(ATARI_PY_TAR_FILENAME);
 	let extract_dir = dir.path().join("extract");
 
-	run_download(PIP_ATARI_PY_TAR_URL, &tar_path);
+	let urls = match url {
+		Some(url) => vec![url],
+		None => ATARI_PY_TAR_URLS.to_vec(),
+	};
+	run_download_mirrors(&urls, &tar_path);
 	run_extract(&tar_path, &extract_dir);
 
-	let roms_dir = project_root().join("roms");
-	std::fs::create_dir_all(&roms_dir).expect("failed to create roms dir");
+	let mut roms = Vec::new();
 	for rom in std::fs::read_dir(extract_dir.join("atari-py-0.2.6").join("atari_py").join("atari_roms")).expect("failed to read dir") {
 		let rom = rom.expect("failed to read dir");
-		eprintln!("{}copy {}", XTASK_PREFIX, rom.path().file_name().unwrap_or(OsStr::new("")).to_string_lossy());
-		std::fs::copy(rom.path(), roms_dir.join(rom.path().file_name().unwrap()))
-			.expect("failed to copy file");
+		let filename = rom.path().file_name().unwrap_or(OsStr::new("")).to_string_lossy().to_string();
+		let data = std::fs::read(rom.path()).expect("failed to read file");
+		let sha1 = sha1::Sha1::from(&data).digest().to_string();
+		roms.push((filename, data, sha1));
+	}
+	roms.sort_by(|a, b| a.0.cmp(&b.0));
+
+	// The download has been checked against the pinned digest, so its ROMs can be trusted when recording checksums
+	if record_checksums {
+		run_record_checksums(&roms);
+	} else {
+		run_verify_roms(&roms);
+	}
+
+	let roms_dir = project_root().join("roms");
+	std::fs::create_dir_all(&roms_dir).expect("failed to create roms dir");
+	for (filename, data, _) in &roms {
+		eprintln!("{}copy {}", XTASK_PREFIX, filename);
+		std::fs::write(roms_dir.join(filename), data).expect("failed to copy file");
 	}
 }
 
-fn run_download(url: &str, dst: &Path) {
+/// Records the checksums of the downloaded ROMs in the known-good checksums, keeping the comments and the checksums of
+/// other ROMs.
+fn run_record_checksums(roms: &[(String, Vec<u8>, String)]) {
+	let path = project_root().join(ROM_SHA1SUMS_PATH);
+	eprintln!("{}write {}", XTASK_PREFIX, path.display());
+	let existing = read_rom_sha1sums();
+	let mut comments = String::new();
+	let mut sha1sums: Vec<(String, String)> = Vec::new();
+	for line in existing.lines() {
+		if line.starts_with('#') {
+			comments.push_str(line);
+			comments.push('\n');
+		}
+	}
+	for (filename, sha1) in parse_sha1sums(&existing) {
+		if !roms.iter().any(|(rom, _, _)| rom == filename) {
+			sha1sums.push((filename.to_owned(), sha1.to_owned()));
+		}
+	}
+	sha1sums.extend(roms.iter().map(|(filename, _, sha1)| (filename.clone(), sha1.clone())));
+	sha1sums.sort();
+
+	// In the same format as `sha1sum`
+	let lines: String = sha1sums.iter().map(|(filename, sha1)| format!("{}  {}\n", sha1, filename)).collect();
+	std::fs::write(&path, comments + &lines).expect("failed to write checksums");
+}
+
+/// Checks the downloaded ROMs against the known-good checksums, and exits if any of them don't match, or if a bundled
+/// ROM is missing, before any are copied. ROMs that have no known checksum yet are warned about, but still copied.
+fn run_verify_roms(roms: &[(String, Vec<u8>, String)]) {
+	eprintln!("{}verify ROMs against {}", XTASK_PREFIX, ROM_SHA1SUMS_PATH);
+	let expected = read_rom_sha1sums();
+	let expected = parse_sha1sums(&expected);
+
+	let mut verified = 0;
+	let mut unverified = 0;
+	let mut failed = 0;
+	for (filename, _, sha1) in roms {
+		match expected.get(filename.as_str()) {
+			Some(expected_sha1) if *expected_sha1 == sha1 => verified += 1,
+			Some(expected_sha1) => {
+				eprintln!("{}{}: checksum mismatch: expected {}, got {}", ERROR_PREFIX, filename, expected_sha1, sha1);
+				failed += 1;
+			},
+			None => {
+				eprintln!("{}{}: no known checksum, the ROM's checksum is {}", WARNING_PREFIX, filename, sha1);
+				unverified += 1;
+			},
+		}
+	}
+	for filename in bundled_rom_filenames() {
+		if !roms.iter().any(|(rom, _, _)| *rom == filename) {
+			eprintln!("{}{}: missing from the download", ERROR_PREFIX, filename);
+			failed += 1;
+		}
+	}
+
+	eprintln!("{}verified {} ROMs, {} unverified, {} failed", XTASK_PREFIX, verified, unverified, failed);
+	if unverified > 0 {
+		eprintln!("{}some ROMs have no known checksum. To record the checksums of the ROMs in the download, run \
+			`cargo xtask download-roms --record-checksums`", WARNING_PREFIX);
+	}
+	if failed > 0 {
+		eprintln!("{}the downloaded ROMs don't match the known-good checksums, so none have been copied. To record \
+			the checksums of the ROMs in the download, run `cargo xtask download-roms --record-checksums`", ERROR_PREFIX);
+		std::process::exit(1);
+	}
+}
+
+/// Checks that every ROM that `BundledRom` includes is in the `roms/` folder, and matches its known-good checksum, if
+/// it has one.
+fn run_verify_bundled_roms() {
+	let expected = read_rom_sha1sums();
+	let expected = parse_sha1sums(&expected);
+	let filenames = bundled_rom_filenames();
+	let roms_dir = project_root().join("roms");
+
+	let mut unverified = 0;
+	let mut failed = 0;
+	for filename in &filenames {
+		let data = match std::fs::read(roms_dir.join(filename)) {
+			Ok(data) => data,
+			Err(e) => {
+				eprintln!("{}{}: failed to read: {}", ERROR_PREFIX, filename, e);
+				failed += 1;
+				continue;
+			}
+		};
+		let sha1 = sha1::Sha1::from(&data).digest().to_string();
+		match expected.get(filename.as_str()) {
+			Some(expected_sha1) if *expected_sha1 == sha1 => {},
+			Some(expected_sha1) => {
+				eprintln!("{}{}: checksum mismatch: expected {}, got {}", ERROR_PREFIX, filename, expected_sha1, sha1);
+				failed += 1;
+			},
+			None => {
+				eprintln!("{}{}: missing from {}", WARNING_PREFIX, filename, ROM_SHA1SUMS_PATH);
+				unverified += 1;
+			},
+		}
+	}
+
+	let verified = filenames.len() - unverified - failed;
+	eprintln!("{}verified {} ROMs, {} unverified, {} failed", XTASK_PREFIX, verified, unverified, failed);
+	if failed > 0 {
+		eprintln!("{}the roms/ folder doesn't match the bundled ROMs. Run `cargo xtask download-roms` to download \
+			them again", ERROR_PREFIX);
+		std::process::exit(1);
+	}
+}
+
+/// Returns the filenames of the ROMs that `BundledRom` includes.
+///
+/// The `ale` crate can't be used to list the bundled ROMs, as it doesn't compile without them, so they are found by
+/// looking for the `include_bytes!` of each ROM in its source.
+fn bundled_rom_filenames() -> Vec<String> {
+	let lib_path = project_root().join("src").join("lib.rs");
+	let lib = std::fs::read_to_string(&lib_path).expect("failed to read lib.rs");
+	let prefix = "include_bytes!(\"../roms/";
+	let filenames: Vec<String> = lib
+		.match_indices(prefix)
+		.filter_map(|(i, _)| {
+			let rest = &lib[i + prefix.len()..];
+			Some(rest[..rest.find('"')?].to_owned())
+		})
+		.collect();
+	if filenames.is_empty() {
+		eprintln!("{}no bundled ROMs found in {}", ERROR_PREFIX, lib_path.display());
+		std::process::exit(1);
+	}
+	filenames
+}
+
+/// Reads the known-good checksums, exiting if they can't be read.
+fn read_rom_sha1sums() -> String {
+	let path = project_root().join(ROM_SHA1SUMS_PATH);
+	std::fs::read_to_string(&path).unwrap_or_else(|e| {
+		eprintln!("{}failed to read known-good checksums {}: {}", ERROR_PREFIX, path.display(), e);
+		std::process::exit(1);
+	})
+}
+
+/// Parses checksums in the format output by `sha1sum`, into a map from filenames to checksums. Lines starting with `#`
+/// are ignored.
+fn parse_sha1sums(sha1sums: &str) -> HashMap<&str, &str> {
+	sha1sums
+		.lines()
+		.filter(|line| !line.starts_with('#'))
+		.filter_map(|line| {
+			let mut parts = line.split_whitespace();
+			let sha1 = parts.next()?;
+			// `sha1sum` marks files that were read in binary mode with a `*`
+			Some((parts.next()?.trim_start_matches('*'), sha1))
+		})
+		.collect()
+}
+
+/// Downloads from each URL in turn until one succeeds with the expected digest, and exits if they all fail.
+fn run_download_mirrors(urls: &[&str], dst: &Path) {
+	for url in urls {
+		match run_download(url, dst) {
+			Ok(()) => {
+				eprintln!("{}downloaded from {}", XTASK_PREFIX, url);
+				return;
+			},
+			Err(e) => eprintln!("{}failed to download {}: {}", ERROR_PREFIX, url, e),
+		}
+	}
+	eprintln!("{}failed to download from all {} URLs. Set a working URL with --url or {}", ERROR_PREFIX, urls.len(),
+		ATARI_PY_TAR_URL_ENV);
+	std::process::exit(1);
+}
+
+fn run_download(url: &str, dst: &Path) -> Result<(), String> {
 	eprintln!("{}download {}", XTASK_PREFIX, url);
 	let mut out = File::create(&dst).expect("failed to create dst file");
-	let mut res = match reqwest::blocking::get(url) {
-		Err(e) => {
-			eprintln!("{}failed to download {}: {}", ERROR_PREFIX, url, e);
-			::std::process::exit(1);
-		},
-		Ok(r) => r,
-	};
-
+	let mut res = reqwest::blocking::get(url).map_err(|e| e.to_string())?;
 	if !res.status().is_success() {
-		eprintln!("{}failed to download {}: status is {:?}", ERROR_PREFIX, url, res.status());
-		::std::process::exit(1);
+		return Err(format!("status is {:?}", res.status()));
 	}
+	res.copy_to(&mut out).map_err(|e| e.to_string())?;
 
-	if let Err(e) = res.copy_to(&mut out) {
-		eprintln!("{}failed to download {}: {}", ERROR_PREFIX, url, e);
-		::std::process::exit(1);
+	let digest = blake2b_simd::Params::new().hash_length(32).hash(&std::fs::read(dst).map_err(|e| e.to_string())?);
+	if digest.to_hex().as_str() != ATARI_PY_TAR_BLAKE2B_256 {
+		return Err(format!("BLAKE2b-256 digest is {}, expected {}", digest.to_hex(), ATARI_PY_TAR_BLAKE2B_256));
 	}
+	Ok(())
 }
 
 fn run_extract(tar_path: &Path, extract_dir: &Path) {